@@ -91,9 +91,11 @@ impl Action for ConnectAction {
                             .port(connect.port)
                             .ssl(connect.ssl)
                             .serializer(connect.serializer.into())
-                            .tls_options(TlsOptions {
-                                accept_invalid_certs: connect.insecure,
-                            });
+                            .tls_options(
+                                TlsOptions::builder()
+                                    .danger_accept_invalid_certs(connect.insecure)
+                                    .build(),
+                            );
 
                         if let (Some(username), Some(password)) = (connect.user, connect.password) {
                             options_builder = options_builder.credentials(&username, &password);
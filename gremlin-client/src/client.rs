@@ -2,7 +2,7 @@ use crate::io::GraphSON;
 use crate::pool::GremlinConnectionManager;
 use crate::prelude::{
     traversal::Bytecode, ConnectionOptions, GResultSet, GValue, GremlinError, GremlinResult,
-    Message, ToGValue,
+    Message, ServerError, ToGValue,
 };
 use base64::prelude::{Engine, BASE64_STANDARD};
 use futures::future::{BoxFuture, FutureExt};
@@ -93,9 +93,23 @@ impl<SD: GraphSON> GremlinClient<SD> {
     where
         T: Into<String>,
     {
+        let bindings: HashMap<String, GValue> = params
+            .iter()
+            .map(|(k, v)| (String::from(*k), v.to_gvalue()))
+            .collect();
+
+        self.eval(script.into(), bindings).await
+    }
+
+    /// Evaluates `script` with already converted `bindings`.
+    pub(crate) async fn eval(
+        &self,
+        script: String,
+        bindings: HashMap<String, GValue>,
+    ) -> GremlinResult<GResultSet<SD>> {
         let mut args = HashMap::new();
 
-        args.insert(String::from("gremlin"), GValue::String(script.into()));
+        args.insert(String::from("gremlin"), GValue::String(script));
         args.insert(
             String::from("language"),
             GValue::String(String::from("gremlin-groovy")),
@@ -113,17 +127,16 @@ impl<SD: GraphSON> GremlinClient<SD> {
 
         args.insert(String::from("aliases"), GValue::from(aliases));
 
-        let bindings: HashMap<String, GValue> = params
-            .iter()
-            .map(|(k, v)| (String::from(*k), v.to_gvalue()))
-            .collect();
-
         args.insert(String::from("bindings"), GValue::from(bindings));
 
         if let Some(session_name) = &self.session {
             args.insert(String::from("session"), GValue::from(session_name.clone()));
         }
 
+        if let Some(batch_size) = self.options.batch_size {
+            args.insert(String::from("batchSize"), GValue::Int32(batch_size));
+        }
+
         let args = SD::serialize(&GValue::from(args))?;
 
         let processor = if self.session.is_some() {
@@ -191,14 +204,14 @@ impl<SD: GraphSON> GremlinClient<SD> {
 
                         return self.send_message_new(conn, message).await;
                     }
-                    None => Err(GremlinError::Request((
-                        response.status.code,
-                        response.status.message,
+                    None => Err(GremlinError::Server(ServerError::new::<SD>(
+                        response.request_id,
+                        response.status,
                     ))),
                 },
-                _ => Err(GremlinError::Request((
-                    response.status.code,
-                    response.status.message,
+                _ => Err(GremlinError::Server(ServerError::new::<SD>(
+                    response.request_id,
+                    response.status,
                 ))),
             }?;
 
@@ -227,6 +240,10 @@ impl<SD: GraphSON> GremlinClient<SD> {
 
         args.insert(String::from("aliases"), GValue::from(aliases));
 
+        if let Some(batch_size) = self.options.batch_size {
+            args.insert(String::from("batchSize"), GValue::Int32(batch_size));
+        }
+
         let args = SD::serialize(&GValue::from(args))?;
 
         let message = SD::message(
@@ -238,7 +255,12 @@ impl<SD: GraphSON> GremlinClient<SD> {
 
         let conn = self.pool.get().await?;
 
-        self.send_message_new(conn, message).await
+        self.send_message_new(conn, message)
+            .await
+            .map_err(|e| match e {
+                GremlinError::Server(e) => GremlinError::Server(e.with_bytecode(bytecode)),
+                e => e,
+            })
     }
 
     fn build_message<T: Serialize>(&self, msg: Message<T>) -> GremlinResult<String> {
@@ -1,51 +1,63 @@
 use crate::prelude::{ConnectionOptions, GraphSON, GremlinError, GremlinResult};
 
 use crate::message::Response;
+use crate::runtime;
 
+use async_tungstenite::tungstenite::client::IntoClientRequest;
+use async_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
 use async_tungstenite::tungstenite::protocol::{Message, WebSocketConfig};
-use async_tungstenite::WebSocketStream;
-use async_tungstenite::{self, stream};
+use async_tungstenite::{self, stream, WebSocketStream};
 use futures::{
     lock::Mutex,
-    stream::{SplitSink, SplitStream},
+    stream::{BoxStream, SplitSink, SplitStream},
     SinkExt, StreamExt,
 };
 
-use async_tungstenite::tokio::TokioAdapter;
-use async_tungstenite::tokio::{
-    connect_async_with_config, connect_async_with_tls_connector_and_config,
-};
-use futures::channel::mpsc::{channel, Receiver, Sender};
+use futures::channel::mpsc::{channel, unbounded, Receiver, Sender, TrySendError, UnboundedSender};
+use futures_rustls::client::TlsStream;
+use futures_rustls::TlsConnector;
+use rustls_pki_types::ServerName;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::net::TcpStream;
-use tokio::task::{self};
-use tokio_rustls::TlsConnector;
-use tungstenite::{
-    client::{uri_mode, IntoClientRequest},
-    stream::{Mode, NoDelay},
-    Connector,
-};
 use uuid::Uuid;
 
-type WSStream = WebSocketStream<
-    stream::Stream<
-        TokioAdapter<TcpStream>,
-        TokioAdapter<tokio_rustls::client::TlsStream<TcpStream>>,
-    >,
->;
+type WSStream = WebSocketStream<stream::Stream<runtime::TcpStream, TlsStream<runtime::TcpStream>>>;
+
+/// The responses to a request that follow the first one.
+pub(crate) type Responses = BoxStream<'static, GremlinResult<Response>>;
 
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum Cmd {
-    Msg((Sender<GremlinResult<Response>>, Uuid, Vec<u8>)),
+    Msg((ResponseSender, Uuid, Vec<u8>)),
     Pong(Vec<u8>),
     Shutdown,
 }
 
+/// Where the reader delivers the responses of a request: queued until read, or bounded when a
+/// `result_buffer_limit` is set.
+#[derive(Debug, Clone)]
+pub enum ResponseSender {
+    Bounded(Sender<GremlinResult<Response>>),
+    Unbounded(UnboundedSender<GremlinResult<Response>>),
+}
+
+impl ResponseSender {
+    fn try_send(
+        &mut self,
+        response: GremlinResult<Response>,
+    ) -> Result<(), TrySendError<GremlinResult<Response>>> {
+        match self {
+            ResponseSender::Bounded(sender) => sender.try_send(response),
+            ResponseSender::Unbounded(sender) => sender.unbounded_send(response),
+        }
+    }
+}
+
 pub(crate) struct Conn {
     sender: Sender<Cmd>,
     valid: bool,
+    buffer_limit: Option<usize>,
 }
 
 impl std::fmt::Debug for Conn {
@@ -60,61 +72,32 @@ impl Conn {
         T: Into<ConnectionOptions<SD>>,
     {
         let options = options.into();
-        let websocket_url = options.websocket_url();
-        let request = websocket_url
-            .clone()
-            .into_client_request()
-            .map_err(|e| GremlinError::Generic(e.to_string()))?;
-
-        let connector = if let Some(opts) = options.tls_options {
-            let config = opts.config()?;
-            let config = Arc::new(config);
-            Connector::Rustls(config)
-        } else {
-            Connector::Plain
+        let buffer_limit = options.result_buffer_limit;
+
+        let client = match options.connect_timeout {
+            Some(timeout) => runtime::timeout(timeout, open(&options))
+                .await
+                .map_err(|_| {
+                    GremlinError::Generic(format!(
+                        "Unable to connect to {}:{} within {:?}",
+                        options.host, options.port, timeout
+                    ))
+                })??,
+            None => open(&options).await?,
         };
 
-        let url = request.uri();
-        let mode = uri_mode(url).map_err(|e| GremlinError::Generic(e.to_string()))?;
-        let host = request
-            .uri()
-            .host()
-            .ok_or_else(|| GremlinError::Generic("No Hostname".into()))?;
-        let port = url.port_u16().unwrap_or(match mode {
-            Mode::Plain => 80,
-            Mode::Tls => 443,
-        });
-        let mut stream = std::net::TcpStream::connect((host, port))
-            .map_err(|e| GremlinError::Generic(format!("Unable to connect {e:?}")))?;
-        NoDelay::set_nodelay(&mut stream, true)
-            .map_err(|e| GremlinError::Generic(e.to_string()))?;
-
-        let websocket_config = options
-            .websocket_options
-            .as_ref()
-            .map(WebSocketConfig::from);
-
-        let (client, _) = match connector {
-            Connector::Plain => connect_async_with_config(url, websocket_config).await,
-            Connector::Rustls(config) => {
-                let connector = TlsConnector::from(config);
-                connect_async_with_tls_connector_and_config(url, Some(connector), websocket_config)
-                    .await
-            }
-            _ => panic!(),
-        }?;
-
         let (sink, stream) = client.split();
         let (sender, receiver) = channel(20);
         let requests = Arc::new(Mutex::new(HashMap::new()));
 
         sender_loop(sink, requests.clone(), receiver);
 
-        receiver_loop(stream, requests.clone(), sender.clone());
+        receiver_loop(stream, requests.clone(), sender.clone(), buffer_limit);
 
         Ok(Conn {
             sender,
             valid: true,
+            buffer_limit,
         })
     }
 
@@ -122,8 +105,18 @@ impl Conn {
         &mut self,
         id: Uuid,
         payload: Vec<u8>,
-    ) -> GremlinResult<(Response, Receiver<GremlinResult<Response>>)> {
-        let (sender, mut receiver) = channel(1);
+    ) -> GremlinResult<(Response, Responses)> {
+        // The reader never waits on these, see `receiver_loop`.
+        let (sender, mut receiver): (_, Responses) = match self.buffer_limit {
+            Some(limit) => {
+                let (sender, receiver) = channel(limit);
+                (ResponseSender::Bounded(sender), receiver.boxed())
+            }
+            None => {
+                let (sender, receiver) = unbounded();
+                (ResponseSender::Unbounded(sender), receiver.boxed())
+            }
+        };
 
         self.sender
             .send(Cmd::Msg((sender, id, payload)))
@@ -133,10 +126,15 @@ impl Conn {
                 e
             })?;
 
+        // The reader drops the sender without a reply once the connection is closed.
         receiver
             .next()
             .await
-            .expect("It should contain the response")
+            .unwrap_or_else(|| {
+                Err(GremlinError::WebsocketClone(String::from(
+                    "Connection closed",
+                )))
+            })
             .map(|r| (r, receiver))
             .map_err(|e| {
                 //If there's been an websocket layer error, mark the connection as invalid
@@ -155,6 +153,78 @@ impl Conn {
     }
 }
 
+/// Dials the server and performs the TLS and WebSocket handshakes on the socket, the latter
+/// carrying the configured headers.
+async fn open<SD: GraphSON>(options: &ConnectionOptions<SD>) -> GremlinResult<WSStream> {
+    let url = options.websocket_url();
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| GremlinError::Generic(e.to_string()))?;
+
+    let mut headers = vec![(String::from("User-Agent"), options.user_agent.clone())];
+    if let Some(subprotocol) = &options.subprotocol {
+        headers.push((String::from("Sec-WebSocket-Protocol"), subprotocol.clone()));
+    }
+    headers.extend(options.headers.iter().cloned());
+    if let Some(provider) = &options.header_provider {
+        headers.extend(provider.headers(&url)?);
+    }
+    for (name, value) in headers {
+        let header = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| GremlinError::Generic(format!("Invalid header {}: {}", name, e)))?;
+        let value = HeaderValue::from_str(&value).map_err(|e| {
+            GremlinError::Generic(format!("Invalid value for header {}: {}", name, e))
+        })?;
+        request.headers_mut().insert(header, value);
+    }
+
+    let host = request
+        .uri()
+        .host()
+        .ok_or_else(|| GremlinError::Generic("No Hostname".into()))?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+
+    let proxy = match &options.proxy {
+        Some(proxy) => proxy.proxy_for(&host, options.ssl)?,
+        None => None,
+    };
+    let socket = match proxy {
+        Some(proxy) => {
+            proxy
+                .tunnel(&host, options.port, options.tcp_keepalive)
+                .await
+        }
+        None => runtime::dial(&host, options.port, options.tcp_keepalive).await,
+    }
+    .map_err(|e| GremlinError::Generic(format!("Unable to connect {e:?}")))?;
+
+    let socket = if options.ssl {
+        let tls = options.tls_options.clone().unwrap_or_default();
+        let config = tls.config()?;
+        let domain = ServerName::try_from(tls.server_name.unwrap_or(host))
+            .map_err(|e| GremlinError::Generic(e.to_string()))?;
+        let socket = TlsConnector::from(Arc::new(config))
+            .connect(domain, socket)
+            .await
+            .map_err(|e| GremlinError::WebSocketTlsHandshake(e.to_string()))?;
+        stream::Stream::Tls(socket)
+    } else {
+        stream::Stream::Plain(socket)
+    };
+
+    let websocket_config = options
+        .websocket_options
+        .as_ref()
+        .map(WebSocketConfig::from);
+
+    let (client, _) =
+        async_tungstenite::client_async_with_config(request, socket, websocket_config).await?;
+    Ok(client)
+}
+
 impl Drop for Conn {
     fn drop(&mut self) {
         send_shutdown(self);
@@ -167,29 +237,25 @@ fn send_shutdown(conn: &mut Conn) {
 
 fn sender_loop(
     mut sink: SplitSink<WSStream, Message>,
-    requests: Arc<Mutex<HashMap<Uuid, Sender<GremlinResult<Response>>>>>,
+    requests: Arc<Mutex<HashMap<Uuid, ResponseSender>>>,
     mut receiver: Receiver<Cmd>,
 ) {
-    task::spawn(async move {
+    runtime::spawn(async move {
         loop {
             match receiver.next().await {
                 Some(item) => match item {
                     Cmd::Msg(msg) => {
-                        let mut guard = requests.lock().await;
-
-                        guard.insert(msg.1, msg.0);
+                        requests.lock().await.insert(msg.1, msg.0);
 
+                        // Not holding the lock while writing, so responses to other requests
+                        // keep flowing.
                         let result = sink.send(Message::Binary(msg.2)).await;
 
                         if let Err(e) = result {
-                            let mut sender = guard.remove(&msg.1).unwrap();
-                            sender
-                                .send(Err(GremlinError::from(e)))
-                                .await
-                                .expect("Failed to send error");
+                            if let Some(mut sender) = requests.lock().await.remove(&msg.1) {
+                                let _ = sender.try_send(Err(GremlinError::from(e)));
+                            }
                         }
-
-                        drop(guard);
                     }
                     Cmd::Pong(data) => {
                         sink.send(Message::Pong(data))
@@ -212,10 +278,11 @@ fn sender_loop(
 
 fn receiver_loop(
     mut stream: SplitStream<WSStream>,
-    requests: Arc<Mutex<HashMap<Uuid, Sender<GremlinResult<Response>>>>>,
+    requests: Arc<Mutex<HashMap<Uuid, ResponseSender>>>,
     mut sender: Sender<Cmd>,
+    buffer_limit: Option<usize>,
 ) {
-    task::spawn(async move {
+    runtime::spawn(async move {
         // let span = tracing::span!(tracing::Level::DEBUG, "rx");
         // let _enter = span.enter();
 
@@ -224,43 +291,56 @@ fn receiver_loop(
                 Some(Err(error)) => {
                     let err_str = error.to_string();
                     let mut guard = requests.lock().await;
-                    for s in guard.values_mut() {
+                    for (_, s) in guard.drain() {
                         let error = Err(GremlinError::WebsocketClone(err_str.clone()));
-                        match s.send(error).await {
-                            Ok(_r) => {}
-                            Err(_e) => {}
-                        }
+                        // A fresh sender always has room for one message.
+                        let _ = s.clone().try_send(error);
                     }
-                    guard.clear();
                 }
                 Some(Ok(item)) => match item {
                     Message::Binary(data) => {
-                        let response: Response = serde_json::from_slice(&data).unwrap();
+                        let response: Response = match serde_json::from_slice(&data) {
+                            Ok(response) => response,
+                            Err(e) => {
+                                tracing::warn!("Discarding malformed response: {}", e);
+                                continue;
+                            }
+                        };
 
                         tracing::trace!(
                             request = &response.request_id.to_string(),
                             status = &response.status.code
                         );
 
+                        let id = response.request_id;
+                        let last = response.status.code != 206;
                         let mut guard = requests.lock().await;
-                        if response.status.code != 206 {
-                            let item = guard.remove(&response.request_id);
-                            drop(guard);
-                            if let Some(mut s) = item {
-                                match s.send(Ok(response)).await {
-                                    Ok(_r) => {}
-                                    Err(_e) => {}
-                                };
+
+                        // Never wait for a consumer here: this loop serves every request on the
+                        // connection. Responses queue up until read, unless a limit is set, in
+                        // which case a slow consumer fails its own request instead.
+                        let delivered = match guard.get_mut(&id) {
+                            Some(s) => s.try_send(Ok(response)),
+                            None => continue,
+                        };
+
+                        match delivered {
+                            Ok(()) if !last => {}
+                            Err(e) if e.is_full() => {
+                                let error = GremlinError::Generic(format!(
+                                    "Result buffer overflow for request {}: the consumer fell \
+                                     more than {} responses behind",
+                                    id,
+                                    buffer_limit.unwrap_or_default()
+                                ));
+                                if let Some(s) = guard.remove(&id) {
+                                    let _ = s.clone().try_send(Err(error));
+                                }
                             }
-                        } else {
-                            let item = guard.get_mut(&response.request_id);
-                            if let Some(s) = item {
-                                match s.send(Ok(response)).await {
-                                    Ok(_r) => {}
-                                    Err(_e) => {}
-                                };
+                            // Either complete or the consumer is gone.
+                            _ => {
+                                guard.remove(&id);
                             }
-                            drop(guard);
                         }
                     }
                     Message::Ping(data) => {
@@ -281,10 +361,8 @@ mod tests {
     use super::*;
     use crate::prelude::V3g;
 
-    #[tokio::test]
-    async fn it_should_connect() {
-        Conn::connect::<V3g, _>(("localhost", 8182u16))
-            .await
-            .unwrap();
+    #[test]
+    fn it_should_connect() {
+        runtime::block_on(Conn::connect::<V3g, _>(("localhost", 8182u16))).unwrap();
     }
 }
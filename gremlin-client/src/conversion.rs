@@ -1,4 +1,7 @@
-use crate::prelude::{traversal::Bytecode, P as Predicate, *};
+use crate::prelude::{
+    traversal::{Binding, Bytecode},
+    P as Predicate, *,
+};
 
 // use crate::prelude::{
 //     process::traversal::Bytecode,
@@ -89,6 +92,12 @@ impl ToGValue for Bytecode {
     }
 }
 
+impl ToGValue for Binding {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Binding(self.clone())
+    }
+}
+
 // Take from GValue
 
 #[doc(hidden)]
@@ -117,11 +126,14 @@ impl_from_gvalue!(VertexProperty, GValue::VertexProperty);
 impl_from_gvalue!(Property, GValue::Property);
 impl_from_gvalue!(Map, GValue::Map);
 impl_from_gvalue!(Set, GValue::Set);
+impl_from_gvalue!(BulkSet, GValue::BulkSet);
 impl_from_gvalue!(List, GValue::List);
 impl_from_gvalue!(Token, GValue::Token);
 impl_from_gvalue!(Vertex, GValue::Vertex);
 impl_from_gvalue!(Edge, GValue::Edge);
 impl_from_gvalue!(Path, GValue::Path);
+impl_from_gvalue!(Tree, GValue::Tree);
+impl_from_gvalue!(TinkerGraph, GValue::TinkerGraph);
 impl_from_gvalue!(String, GValue::String);
 impl_from_gvalue!(f32, GValue::Float);
 impl_from_gvalue!(f64, GValue::Double);
@@ -180,6 +192,7 @@ impl<T: FromGValue> FromGValue for Vec<T> {
                     l.take().into_iter().map(T::from_gvalue).collect();
                 Ok(results?)
             }
+            GValue::BulkSet(b) => b.into_iter().map(T::from_gvalue).collect(),
             _ => Err(GremlinError::Cast(format!(
                 "Cannot convert {:?} to List of T",
                 v
@@ -218,6 +231,8 @@ impl_borrow_from_gvalue!(List, GValue::List);
 impl_borrow_from_gvalue!(Vertex, GValue::Vertex);
 impl_borrow_from_gvalue!(Edge, GValue::Edge);
 impl_borrow_from_gvalue!(Path, GValue::Path);
+impl_borrow_from_gvalue!(Tree, GValue::Tree);
+impl_borrow_from_gvalue!(TinkerGraph, GValue::TinkerGraph);
 impl_borrow_from_gvalue!(String, GValue::String);
 impl_borrow_from_gvalue!(Token, GValue::Token);
 impl_borrow_from_gvalue!(f32, GValue::Float);
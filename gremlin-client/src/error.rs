@@ -1,5 +1,9 @@
-use crate::structure::GValue;
+use crate::io::GraphSON;
+use crate::message::{ReponseStatus, ResponseStatusCode};
+use crate::process::traversal::Bytecode;
+use crate::structure::{GValue, Map};
 use std::sync::Arc;
+use uuid::Uuid;
 
 use thiserror::Error;
 
@@ -26,8 +30,14 @@ pub enum GremlinError {
     #[error("JSON error: {0}")]
     Json(String),
 
-    #[error("Request error: {0:?} ")]
-    Request((i16, String)),
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Parse error at offset {0}: {1}")]
+    Parse(usize, String),
+
+    #[error(transparent)]
+    Server(ServerError),
 
     #[error(transparent)]
     Serde(#[from] serde_json::Error),
@@ -58,3 +68,144 @@ impl From<mobc::Error<GremlinError>> for GremlinError {
         }
     }
 }
+
+/// A request failed by Gremlin Server, with the details the server reported.
+#[derive(Debug, Clone, Error)]
+#[error("Server error {} ({:?}): {message}", .code.code(), .code)]
+pub struct ServerError {
+    code: ResponseStatusCode,
+    message: String,
+    attributes: Map,
+    request_id: Uuid,
+    bytecode: Option<Bytecode>,
+}
+
+impl ServerError {
+    pub(crate) fn new<SD: GraphSON>(request_id: Uuid, status: ReponseStatus) -> ServerError {
+        let attributes = status.decode_attributes::<SD>();
+        ServerError {
+            code: status.code.into(),
+            message: status.message,
+            attributes,
+            request_id,
+            bytecode: None,
+        }
+    }
+
+    pub(crate) fn with_bytecode(mut self, bytecode: &Bytecode) -> ServerError {
+        self.bytecode = Some(bytecode.clone());
+        self
+    }
+
+    pub fn code(&self) -> ResponseStatusCode {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The status attributes, e.g. `exceptions` and `stackTrace`.
+    pub fn attributes(&self) -> &Map {
+        &self.attributes
+    }
+
+    /// The class names of the exceptions that failed the request, outermost first.
+    pub fn exceptions(&self) -> Vec<&str> {
+        match self.attributes.get("exceptions") {
+            Some(GValue::List(exceptions)) => exceptions
+                .iter()
+                .filter_map(|e| match e {
+                    GValue::String(e) => Some(e.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether an exception with this class name, qualified or simple, failed the request,
+    /// e.g. `ConcurrentModificationException` for a conflicting write.
+    pub fn has_exception(&self, class: &str) -> bool {
+        self.exceptions()
+            .iter()
+            .any(|e| *e == class || e.rsplit('.').next() == Some(class))
+    }
+
+    pub fn stack_trace(&self) -> Option<&str> {
+        match self.attributes.get("stackTrace") {
+            Some(GValue::String(trace)) => Some(trace),
+            _ => None,
+        }
+    }
+
+    pub fn request_id(&self) -> &Uuid {
+        &self.request_id
+    }
+
+    /// The traversal that failed, for requests submitting bytecode.
+    pub fn bytecode(&self) -> Option<&Bytecode> {
+        self.bytecode.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{V2, V3};
+    use serde_json::json;
+
+    fn status(attributes: serde_json::Value) -> ReponseStatus {
+        serde_json::from_value(json!({
+            "code": 500,
+            "message": "Lock expired",
+            "attributes": attributes,
+        }))
+        .expect("Failed to parse test status")
+    }
+
+    #[test]
+    fn it_should_decode_v3_attributes() {
+        let status = status(json!({
+            "@type": "g:Map",
+            "@value": [
+                "exceptions",
+                {"@type": "g:List", "@value": ["java.util.ConcurrentModificationException"]},
+                "stackTrace",
+                "at line 1",
+            ],
+        }));
+        let error = ServerError::new::<V3>(Uuid::new_v4(), status);
+
+        assert_eq!(ResponseStatusCode::ServerError, error.code());
+        assert_eq!(
+            vec!["java.util.ConcurrentModificationException"],
+            error.exceptions()
+        );
+        assert!(error.has_exception("ConcurrentModificationException"));
+        assert!(error.has_exception("java.util.ConcurrentModificationException"));
+        assert!(!error.has_exception("ModificationException"));
+        assert_eq!(Some("at line 1"), error.stack_trace());
+    }
+
+    #[test]
+    fn it_should_decode_v2_attributes() {
+        let status = status(json!({"exceptions": ["java.util.ConcurrentModificationException"]}));
+        let error = ServerError::new::<V2>(Uuid::new_v4(), status);
+
+        assert!(error.has_exception("ConcurrentModificationException"));
+        assert_eq!(None, error.stack_trace());
+    }
+
+    #[test]
+    fn it_should_tolerate_missing_attributes() {
+        let error = ServerError::new::<V3>(Uuid::new_v4(), status(json!({})));
+
+        assert!(error.attributes().is_empty());
+        assert!(error.exceptions().is_empty());
+        assert_eq!(
+            "Server error 500 (ServerError): Lock expired",
+            GremlinError::Server(error).to_string()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::io::serde::v2::types::*;
 use crate::io::{GraphSONDeserializer, V2};
 use crate::prelude::{GremlinError, GremlinResult};
+use crate::process::traversal::Binding;
 use crate::structure::*;
 use chrono::{TimeZone, Utc};
 use serde_json::Value;
@@ -62,15 +63,15 @@ fn core_deserializer<D: GraphSONDeserializer>(
         VERTEX => vertex::<D>(value),
         VERTEX_PROPERTY => vertex_property::<D>(value),
         BARRIER => todo!("support"),
-        BINDING => todo!("support"),
+        BINDING => binding::<D>(value),
         BYTECODE => todo!("support"),
         CARDINALITY => todo!("support"),
         COLUMN => todo!("support"),
         DIRECTION => direction(value),
-        DT => todo!("support"),
-        LAMBDA => todo!("support"),
+        DT => dt(value),
+        LAMBDA => lambda(value),
         MERGE => todo!("support"),
-        METRICS => todo!("support"),
+        METRICS => metrics::<D>(value),
         OPERATOR => todo!("support"),
         ORDER => todo!("support"),
         P => todo!("support"),
@@ -79,7 +80,8 @@ fn core_deserializer<D: GraphSONDeserializer>(
         SCOPE => todo!("support"),
         T => token(value),
         TEXT_P => todo!("support"),
-        TRAVERSAL_METRICS => metrics::<D>(value),
+        TRAVERSAL_EXPLANATION => explain::<D>(value),
+        TRAVERSAL_METRICS => traversal_metrics::<D>(value),
         TRAVERSER => traverser::<D>(value),
 
         type_tag => Err({
@@ -222,7 +224,37 @@ pub fn direction(val: &Value) -> GremlinResult<GValue> {
     }
 }
 
-fn tree<D: GraphSONDeserializer>(val: &Value) -> GremlinResult<GValue> {
+/// Binding deserializer [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_binding)
+pub fn binding<D: GraphSONDeserializer>(val: &Value) -> GremlinResult<GValue> {
+    let key = get_value!(&val["key"], Value::String)?;
+    let value = D::deserialize(&val["value"])?;
+    Ok(Binding::new(key.clone(), value).into())
+}
+
+/// Lambda deserializer [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_lambda)
+pub fn lambda(val: &Value) -> GremlinResult<GValue> {
+    let script = get_value!(&val["script"], Value::String)?;
+    let language = get_value!(&val["language"], Value::String)?;
+    let arguments = expect_i32!(&val["arguments"]);
+    Ok(Lambda::with_language(script.clone(), language.clone())
+        .arguments(arguments)
+        .into())
+}
+
+/// DT deserializer [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_dt)
+pub fn dt(val: &Value) -> GremlinResult<GValue> {
+    let val = get_value!(val, Value::String)?;
+    match val.as_str() {
+        "second" => Ok(GValue::DT(crate::structure::DT::Second)),
+        "minute" => Ok(GValue::DT(crate::structure::DT::Minute)),
+        "hour" => Ok(GValue::DT(crate::structure::DT::Hour)),
+        "day" => Ok(GValue::DT(crate::structure::DT::Day)),
+        other => Err(GremlinError::Cast(format!("Unknown DT literal {other}"))),
+    }
+}
+
+/// Tree deserializer [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_tree)
+pub fn tree<D: GraphSONDeserializer>(val: &Value) -> GremlinResult<GValue> {
     let array = get_value!(val, Value::Array)?;
     let branches = array
         .into_iter()
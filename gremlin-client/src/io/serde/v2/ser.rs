@@ -33,13 +33,13 @@ impl GraphSONSerializer for V2 {
             GValue::VertexProperty(_) => vertex_property::<Self>(value),
             // Process
             // GValue::Barrier(_) => todo!("v2::barrier"),
-            // GValue::Binding(_) => todo!("v2::binding"),
+            GValue::Binding(_) => binding::<Self>(value),
             GValue::Bytecode(_) => bytecode::<Self>(value),
             GValue::Cardinality(_) => cardinality(value),
             GValue::Column(_) => column(value),
             GValue::Direction(_) => direction(value),
-            // GValue::DT(_) => todo!("v2::dt"),
-            // GValue::Lambda(_) => todo!("v2::lambda"),
+            GValue::DT(_) => dt(value),
+            GValue::Lambda(_) => lambda(value),
             GValue::Merge(_) => merge(value),
             // GValue::Metrics(_) => todo!("v2::metrics"),
             // GValue::Operator(_) => todo!("v2::operator"),
@@ -48,17 +48,18 @@ impl GraphSONSerializer for V2 {
             // GValue::Pick(_) => todo!("v2::pick"),
             GValue::Pop(_) => pop(value),
             GValue::Scope(_) => scope(value),
+            GValue::Strategy(_) => strategy::<Self>(value),
             GValue::T(_) => t(value),
             GValue::TextP(_) => text_p::<Self>(value),
+            GValue::TraversalExplanation(_) => explain::<Self>(value),
             GValue::TraversalMetrics(_) => todo!("v2::traversalmetrics"),
-            GValue::Traverser(_) => todo!("v2::traverser"),
+            GValue::Traverser(_) => traverser::<Self>(value),
 
             GValue::List(_) => list::<Self>(value),
             // GValue::Set(_) => set::<Self>(value),
             // GValue::P(_) => p::<Self>(value),
-
-            // GValue::Map(_) => map::<Self>(value),
-            // GValue::Bool(_) => bool(value),
+            GValue::Map(_) => map::<Self>(value),
+            GValue::Bool(_) => bool(value),
             GValue::Null => Ok(serde_json::Value::Null),
             value => panic!("Unsupported type {:?}", value),
         }
@@ -478,6 +479,84 @@ pub fn text_p<S: GraphSONSerializer>(value: &GValue) -> GremlinResult<Value> {
     }))
 }
 
+pub fn strategy<S: GraphSONSerializer>(value: &GValue) -> GremlinResult<Value> {
+    let strategy = get_value!(value, GValue::Strategy)?;
+    let mut configuration = Map::new();
+    for (key, value) in strategy.configuration() {
+        configuration.insert(key.clone(), S::serialize(value)?);
+    }
+    Ok(json!({
+        "@type" : format!("g:{}", strategy.name()),
+        "@value" : configuration,
+    }))
+}
+
+pub fn binding<S: GraphSONSerializer>(value: &GValue) -> GremlinResult<Value> {
+    let binding = get_value!(value, GValue::Binding)?;
+    Ok(json!({
+        "@type" : BINDING,
+        "@value" : {
+            "key" : binding.key(),
+            "value" : S::serialize(binding.value())?,
+        },
+    }))
+}
+
+pub fn traverser<S: GraphSONSerializer>(value: &GValue) -> GremlinResult<Value> {
+    let traverser = get_value!(value, GValue::Traverser)?;
+    Ok(json!({
+        "@type" : TRAVERSER,
+        "@value" : {
+            "bulk" : S::serialize(&GValue::Int64(traverser.bulk()))?,
+            "value" : S::serialize(traverser.value())?,
+        },
+    }))
+}
+
+pub fn explain<S: GraphSONSerializer>(value: &GValue) -> GremlinResult<Value> {
+    let explain = get_value!(value, GValue::TraversalExplanation)?;
+    let strings = |steps: &Vec<String>| -> GValue {
+        steps
+            .iter()
+            .cloned()
+            .map(GValue::String)
+            .collect::<Vec<_>>()
+            .into()
+    };
+    let intermediate: Vec<GValue> = explain
+        .intermediate()
+        .iter()
+        .map(|i| {
+            let mut repr = HashMap::new();
+            repr.insert(String::from("traversal"), strings(i.traversal()));
+            repr.insert(String::from("strategy"), GValue::from(i.strategy().clone()));
+            repr.insert(String::from("category"), GValue::from(i.category().clone()));
+            GValue::from(repr)
+        })
+        .collect();
+
+    let mut map = HashMap::new();
+    map.insert(String::from("original"), strings(explain.original()));
+    map.insert(String::from("final"), strings(explain.final_t()));
+    map.insert(String::from("intermediate"), intermediate.into());
+    Ok(json!({
+        "@type" : TRAVERSAL_EXPLANATION,
+        "@value" : S::serialize(&GValue::from(map))?,
+    }))
+}
+
+pub fn lambda(value: &GValue) -> GremlinResult<Value> {
+    let lambda = get_value!(value, GValue::Lambda)?;
+    Ok(json!({
+        "@type" : LAMBDA,
+        "@value" : {
+            "script" : lambda.script(),
+            "language" : lambda.language(),
+            "arguments" : lambda.arguments_count(),
+        },
+    }))
+}
+
 pub fn pop(value: &GValue) -> GremlinResult<Value> {
     let pop = get_value!(value, GValue::Pop)?;
     Ok(json!({
@@ -486,6 +565,14 @@ pub fn pop(value: &GValue) -> GremlinResult<Value> {
     }))
 }
 
+pub fn dt(value: &GValue) -> GremlinResult<Value> {
+    let dt = get_value!(value, GValue::DT)?;
+    Ok(json!({
+        "@type": DT,
+        "@value": dt.to_string(),
+    }))
+}
+
 pub fn cardinality(value: &GValue) -> GremlinResult<Value> {
     let cardinality = get_value!(value, GValue::Cardinality)?;
     let v = match cardinality {
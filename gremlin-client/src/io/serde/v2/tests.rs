@@ -951,7 +951,7 @@ mod process {
         V2,
         Test {
             serial: json!({ "@type" : "g:Binding", "@value" : { "key" : "x", "value" : { "@type" : "g:Int32", "@value" : 1 } }}),
-            object: GValue::Null,
+            object: GValue::Binding(crate::process::traversal::Binding::new("x", 1)),
         }
     );
     test!(
@@ -1023,7 +1023,7 @@ mod process {
         V2,
         Test {
             serial: json!({ "@type" : "g:Lambda", "@value" : { "script" : "{ it.get() }", "language" : "gremlin-groovy", "arguments" : 1 }}),
-            object: GValue::Null,
+            object: GValue::Lambda(Lambda::new("{ it.get() }").arguments(1)),
         }
     );
     test!(
@@ -23,10 +23,14 @@ impl GraphSONDeserializer for V3 {
                     LIST => list::<Self>(&value["@value"]),
                     MAP => map::<Self>(&value["@value"]),
                     PATH => path::<Self>(&value["@value"]),
+                    TINKER_GRAPH => tinker_graph::<Self>(&value["@value"]),
+                    TREE => tree::<Self>(&value["@value"]),
                     METRICS => metrics::<Self>(&value["@value"]),
                     TRAVERSAL_METRICS => traversal_metrics::<Self>(&value["@value"]),
+                    TRAVERSAL_EXPLANATION => explain::<Self>(&value["@value"]),
                     SET => set::<Self>(&value["@value"]),
                     BULK_SET => bulkset::<Self>(&value["@value"]),
+                    BINDING => binding::<Self>(&value["@value"]),
                     _ => V2::deserialize(value),
                 }
             }
@@ -70,11 +74,12 @@ pub(crate) fn map<D: GraphSONDeserializer>(val: &Value) -> GremlinResult<GValue>
     Ok(map.into())
 }
 
-/// Bulkset deserializer [docs](https://tinkerpop.apache.org/docs/3.4.1/dev/io/#_bulkset)
+/// Bulkset deserializer [docs](https://tinkerpop.apache.org/docs/3.4.1/dev/io/#_bulkset),
+/// keeping each value once with its bulk rather than repeating it.
 pub(crate) fn bulkset<D: GraphSONDeserializer>(val: &Value) -> GremlinResult<GValue> {
     if val.to_string().contains("[null]") {
         // TODO Gremlin docs!
-        return Ok(GValue::List(List::new(vec![])));
+        return Ok(BulkSet::default().into());
     }
 
     let val = get_value!(val, Value::Array)?;
@@ -85,40 +90,17 @@ pub(crate) fn bulkset<D: GraphSONDeserializer>(val: &Value) -> GremlinResult<GVa
         ));
     }
 
-    let mut data = vec![];
-    let mut counts = vec![];
-
-    for gval in val.clone().into_iter() {
-        if data.len() > counts.len() {
-            counts.push(D::deserialize(&gval)?);
-        } else {
-            data.push(D::deserialize(&gval)?);
-        }
-    }
-
-    let hashmap = data
-        .clone()
-        .into_iter()
-        .flat_map(|val| {
-            let key_opt = match val {
-                GValue::Map(ref map) => match map.get("id") {
-                    Some(GValue::Int64(i)) => Some(GKey::String(i.to_string())),
-                    Some(GValue::Int32(i)) => Some(GKey::String(i.to_string())),
-                    Some(GValue::String(s)) => Some(GKey::String(s.to_string())),
-                    _ => None,
-                },
-                _ => None,
-            };
-            // TODO very bad practice but it works so .. :D
-            if key_opt.is_some() {
-                Some((key_opt.unwrap(), val))
-            } else {
-                None
-            }
+    // Values are followed by their bulk.
+    let bulks = val
+        .chunks(2)
+        .map(|pair| {
+            let value = D::deserialize(&pair[0])?;
+            let bulk = D::deserialize(&pair[1])?.take::<i64>()?;
+            Ok((value, bulk))
         })
-        .collect::<HashMap<GKey, GValue>>();
+        .collect::<GremlinResult<Vec<_>>>()?;
 
-    Ok(GValue::BulkSet(Map::from(hashmap)))
+    Ok(BulkSet::new(bulks).into())
 }
 
 /// Traversal Metrics deserializer [docs](http://tinkerpop.apache.org/docs/3.4.1/dev/io/#_traversalmetrics)
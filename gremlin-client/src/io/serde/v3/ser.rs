@@ -22,6 +22,9 @@ impl GraphSONSerializer for V3 {
             GValue::Path(_) => path::<Self>(value),
             GValue::Merge(_) => merge(value),
             GValue::T(_) => t(value),
+            GValue::Strategy(_) => strategy::<Self>(value),
+            GValue::Binding(_) => binding::<Self>(value),
+            GValue::Traverser(_) => traverser::<Self>(value),
             _ => V2::serialize(value),
         }
     }
@@ -175,6 +175,55 @@ mod structure {
         }
     );
 }
+
+mod nested {
+    use crate::io::V3;
+    use crate::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn tinkergraph_with_v3_values() {
+        let serial = json!({ "@type" : "tinker:graph", "@value" : { "vertices" : [ { "@type" : "g:Vertex", "@value" : { "id" : { "@type" : "g:Int32", "@value" : 1 }, "label" : "person", "properties" : { "nicknames" : [ { "@type" : "g:VertexProperty", "@value" : { "id" : { "@type" : "g:Int64", "@value" : 0 }, "value" : { "@type" : "g:List", "@value" : [ "marko", "mr" ] }, "label" : "nicknames" } } ] } } }, { "@type" : "g:Vertex", "@value" : { "id" : { "@type" : "g:Int32", "@value" : 10 }, "label" : "software" } } ], "edges" : [ { "@type" : "g:Edge", "@value" : { "id" : { "@type" : "g:Int32", "@value" : 13 }, "label" : "develops", "inVLabel" : "software", "outVLabel" : "person", "inV" : { "@type" : "g:Int32", "@value" : 10 }, "outV" : { "@type" : "g:Int32", "@value" : 1 } } } ] }});
+
+        let graph = V3::deserialize(&serial)
+            .unwrap()
+            .take::<TinkerGraph>()
+            .unwrap();
+
+        assert_eq!(2, graph.vertices().len());
+        assert_eq!(1, graph.edges().len());
+        let marko = graph.vertex(&GID::Int32(1)).unwrap();
+        assert_eq!(
+            &GValue::List(vec!["marko".into(), "mr".into()].into()),
+            marko.property("nicknames").unwrap().value()
+        );
+        assert_eq!(
+            vec![&GID::Int32(10)],
+            graph
+                .adjacent(&GID::Int32(1), Direction::Out)
+                .into_iter()
+                .map(Vertex::id)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn tree_with_v3_keys() {
+        let serial = json!({ "@type" : "g:Tree", "@value" : [ { "key" : { "@type" : "g:Map", "@value" : [ "name", { "@type" : "g:List", "@value" : [ "marko" ] } ] }, "value" : { "@type" : "g:Tree", "@value" : [ { "key" : { "@type" : "g:Int64", "@value" : 3 }, "value" : { "@type" : "g:Tree", "@value" : [ ] } } ] } } ]});
+
+        let tree = V3::deserialize(&serial).unwrap().take::<Tree>().unwrap();
+
+        let mut marko = std::collections::HashMap::new();
+        marko.insert(
+            String::from("name"),
+            GValue::List(vec!["marko".into()].into()),
+        );
+        let marko = GValue::from(marko);
+        assert_eq!(vec![&marko], tree.keys().collect::<Vec<_>>());
+        let leaf = tree.walk(&[marko, GValue::Int64(3)]).unwrap();
+        assert!(leaf.is_empty());
+    }
+}
 mod process {
     pub(self) use super::*;
 
@@ -193,7 +242,7 @@ mod process {
         V3,
         Test {
             serial: json!({ "@type" : "g:Binding", "@value" : { "key" : "x", "value" : { "@type" : "g:Int32", "@value" : 1 } }}),
-            object: GValue::Null,
+            object: GValue::Binding(crate::process::traversal::Binding::new("x", 1)),
         }
     );
     test!(
@@ -201,7 +250,10 @@ mod process {
         V3,
         Test {
             serial: json!({ "@type" : "g:BulkSet", "@value" : [ "marko", { "@type" : "g:Int64", "@value" : 1 }, "josh", { "@type" : "g:Int64", "@value" : 2 } ]}),
-            object: GValue::Null,
+            object: GValue::BulkSet(BulkSet::new(vec![
+                (GValue::from("marko"), 1),
+                (GValue::from("josh"), 2),
+            ])),
         }
     );
     test!(
@@ -236,6 +288,14 @@ mod process {
             object: GValue::Null,
         }
     );
+    test!(
+        dt,
+        V3,
+        Test {
+            serial: json!({ "@type" : "g:DT", "@value" : "day"}),
+            object: GValue::DT(DT::Day),
+        }
+    );
     test!(
         operator,
         V3,
@@ -273,7 +333,7 @@ mod process {
         V3,
         Test {
             serial: json!({ "@type" : "g:Lambda", "@value" : { "script" : "{ it.get() }", "language" : "gremlin-groovy", "arguments" : 1 }}),
-            object: GValue::Null,
+            object: GValue::Lambda(Lambda::new("{ it.get() }").arguments(1)),
         }
     );
     test!(
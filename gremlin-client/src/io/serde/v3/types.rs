@@ -18,7 +18,8 @@ graphson_types! {
     EDGE, "g:Edge",
     PATH, "g:Path",
     PROPERTY, "g:Property",
-    TINKER_GRAPH, "g:TinkerGraph",
+    TINKER_GRAPH, "tinker:graph",
+    TREE, "g:Tree",
     VERTEX, "g:Vertex",
     VERTEX_PROPERTY, "g:VertexProperty"
 
@@ -45,6 +46,7 @@ graphson_types! {
     SCOPE, "g:Scope",
     T, "g:T",
     TEXT_P, "g:TextP",
+    TRAVERSAL_EXPLANATION, "g:TraversalExplanation",
     TRAVERSAL_METRICS, "g:TraversalMetrics",
     TRAVERSER, "g:Traverser"
 }
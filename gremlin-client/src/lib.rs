@@ -9,10 +9,11 @@
 //! gremlin-client = "*"
 //! ```
 //!
-//! For async support via [async-std](https://async.rs/)
+//! The client runs on [tokio](https://tokio.rs/) by default. For
+//! [async-std](https://async.rs/) or [smol](https://github.com/smol-rs/smol) instead
 //! ```toml
 //! [dependencies]
-//! gremlin-client =  { version = "*", features = ["async_std"] }
+//! gremlin-client =  { version = "*", default-features = false, features = ["async-std-runtime"] }
 //! ```
 //!
 //!
@@ -130,19 +131,27 @@ mod connection;
 
 mod options;
 
+mod proxy;
+
+mod runtime;
+
+mod tls;
+
 pub mod process;
 pub mod structure;
 pub mod utils;
 
 pub mod prelude {
-    pub use tokio_stream::StreamExt;
+    pub use futures::StreamExt;
 
-    pub use crate::error::GremlinError;
+    pub use crate::error::{GremlinError, ServerError};
+    pub use crate::message::ResponseStatusCode;
     pub type GremlinResult<T> = Result<T, GremlinError>;
 
     pub use crate::client::GremlinClient;
     pub use crate::io::{GraphSON, GraphSONDeserializer, GraphSONSerializer, V3g, V2, V3};
     pub use crate::options::*;
+    pub use crate::proxy::ProxyOptions;
     pub use crate::{edge, vertex};
 
     pub use crate::process::traversal;
@@ -160,4 +169,5 @@ pub mod prelude {
 pub mod derive {
     pub use gremlin_derive::FromGMap;
     pub use gremlin_derive::FromGValue;
+    pub use gremlin_derive::gremlin;
 }
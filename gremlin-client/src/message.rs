@@ -1,3 +1,5 @@
+use crate::io::GraphSON;
+use crate::structure::{GValue, Map};
 use serde::{Deserialize as SerdeDeserialize, Deserializer};
 use serde_derive::{Deserialize, Serialize};
 use serde_json::Value;
@@ -60,6 +62,15 @@ pub struct Response {
 #[derive(Debug, Deserialize)]
 pub struct ResponseResult {
     pub data: Value,
+    /// Still GraphSON encoded, as decoding depends on the serializer in use.
+    #[serde(default)]
+    pub meta: Value,
+}
+
+impl ResponseResult {
+    pub(crate) fn decode_meta<SD: GraphSON>(&self) -> Map {
+        decode_map::<SD>(&self.meta)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -68,6 +79,92 @@ pub struct ReponseStatus {
     //Sometimes the message is omitted, default to empty string rather than panic
     #[serde(default, deserialize_with = "map_null_to_default")]
     pub message: String,
+    /// Still GraphSON encoded, as decoding depends on the serializer in use.
+    #[serde(default)]
+    pub attributes: Value,
+}
+
+impl ReponseStatus {
+    pub(crate) fn decode_attributes<SD: GraphSON>(&self) -> Map {
+        decode_map::<SD>(&self.attributes)
+    }
+}
+
+// Meta and attributes are informational, so ones we can't decode are left out rather than
+// failing the response they came with.
+fn decode_map<SD: GraphSON>(value: &Value) -> Map {
+    match SD::deserialize(value) {
+        Ok(GValue::Map(map)) => map,
+        _ => Map::empty(),
+    }
+}
+
+/// The status codes of Gremlin Server responses
+/// [docs](https://tinkerpop.apache.org/docs/current/dev/provider/#_graph_driver_provider_requirements)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseStatusCode {
+    Success,
+    NoContent,
+    PartialContent,
+    Unauthorized,
+    Forbidden,
+    Authenticate,
+    TooManyRequests,
+    MalformedRequest,
+    InvalidRequestArguments,
+    ServerError,
+    FailStep,
+    TemporaryServerError,
+    ScriptEvaluationError,
+    ServerTimeout,
+    ServerSerializationError,
+    Other(i16),
+}
+
+impl ResponseStatusCode {
+    pub fn code(&self) -> i16 {
+        match self {
+            ResponseStatusCode::Success => 200,
+            ResponseStatusCode::NoContent => 204,
+            ResponseStatusCode::PartialContent => 206,
+            ResponseStatusCode::Unauthorized => 401,
+            ResponseStatusCode::Forbidden => 403,
+            ResponseStatusCode::Authenticate => 407,
+            ResponseStatusCode::TooManyRequests => 429,
+            ResponseStatusCode::MalformedRequest => 498,
+            ResponseStatusCode::InvalidRequestArguments => 499,
+            ResponseStatusCode::ServerError => 500,
+            ResponseStatusCode::FailStep => 595,
+            ResponseStatusCode::TemporaryServerError => 596,
+            ResponseStatusCode::ScriptEvaluationError => 597,
+            ResponseStatusCode::ServerTimeout => 598,
+            ResponseStatusCode::ServerSerializationError => 599,
+            ResponseStatusCode::Other(code) => *code,
+        }
+    }
+}
+
+impl From<i16> for ResponseStatusCode {
+    fn from(code: i16) -> Self {
+        match code {
+            200 => ResponseStatusCode::Success,
+            204 => ResponseStatusCode::NoContent,
+            206 => ResponseStatusCode::PartialContent,
+            401 => ResponseStatusCode::Unauthorized,
+            403 => ResponseStatusCode::Forbidden,
+            407 => ResponseStatusCode::Authenticate,
+            429 => ResponseStatusCode::TooManyRequests,
+            498 => ResponseStatusCode::MalformedRequest,
+            499 => ResponseStatusCode::InvalidRequestArguments,
+            500 => ResponseStatusCode::ServerError,
+            595 => ResponseStatusCode::FailStep,
+            596 => ResponseStatusCode::TemporaryServerError,
+            597 => ResponseStatusCode::ScriptEvaluationError,
+            598 => ResponseStatusCode::ServerTimeout,
+            599 => ResponseStatusCode::ServerSerializationError,
+            other => ResponseStatusCode::Other(other),
+        }
+    }
 }
 
 fn map_null_to_default<'de, D, T>(de: D) -> Result<T, D::Error>
@@ -80,7 +177,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::message::ReponseStatus;
+    use crate::message::{ReponseStatus, ResponseStatusCode};
 
     #[test]
     fn handle_no_response_status_message() {
@@ -106,4 +203,30 @@ mod tests {
         assert_eq!(123, parsed.code);
         assert_eq!("Hello World", parsed.message);
     }
+
+    #[test]
+    fn handle_response_status_attributes() {
+        let parsed: ReponseStatus = serde_json::from_str(
+            r#"{"code": 597, "message": "boom", "attributes": {"stackTrace": "at line 1"}}"#,
+        )
+        .expect("Failed to parse test message");
+        assert_eq!(
+            ResponseStatusCode::ScriptEvaluationError,
+            ResponseStatusCode::from(parsed.code)
+        );
+        assert_eq!("at line 1", parsed.attributes["stackTrace"]);
+    }
+
+    #[test]
+    fn map_response_status_codes() {
+        for code in &[
+            200, 204, 206, 401, 403, 407, 429, 498, 499, 500, 595, 596, 597, 598, 599,
+        ] {
+            assert_eq!(*code, ResponseStatusCode::from(*code).code());
+        }
+        assert_eq!(
+            ResponseStatusCode::Other(418),
+            ResponseStatusCode::from(418)
+        );
+    }
 }
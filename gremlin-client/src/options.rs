@@ -1,75 +1,188 @@
-use crate::prelude::{GraphSON, GremlinError};
-use rustls_pki_types::pem::PemObject;
-use rustls_pki_types::{CertificateDer, PrivateKeyDer};
-use std::io::BufReader;
+use crate::prelude::{GraphSON, GremlinError, GremlinResult};
+use crate::proxy::ProxyOptions;
+use crate::tls;
+use rustls::client::danger::ServerCertVerifier;
+use rustls::crypto::CryptoProvider;
+use rustls::RootCertStore;
+use std::fmt;
 use std::marker::PhantomData;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use webpki_roots::TLS_SERVER_ROOTS;
 
+/// A certificate or private key, read from a file or given in memory.
 #[derive(Clone, Debug)]
-pub struct TlsOptions {
-    /// A path to your CA file
-    pub authority: Option<PathBuf>,
-    /// A path to your private
-    pub private_key: Option<String>,
-    /// Authentication certificates
-    pub auth_certs: Option<String>,
+pub enum TlsSource {
+    /// A PEM or DER encoded file.
+    File(PathBuf),
+    /// PEM encoded data. Certificate sources may hold a whole chain.
+    Pem(Vec<u8>),
+    /// DER encoded data.
+    Der(Vec<u8>),
 }
 
-impl Default for TlsOptions {
-    fn default() -> Self {
-        Self {
-            authority: None,
-            private_key: None,
-            auth_certs: None,
-        }
+impl From<PathBuf> for TlsSource {
+    fn from(path: PathBuf) -> Self {
+        TlsSource::File(path)
     }
 }
 
-impl TlsOptions {
-    /// Copied pretty directly from https://github.com/rustls/rustls/blob/main/examples/src/bin/tlsclient-mio.rs
-    /// and https://github.com/rustls/tokio-rustls/blob/main/examples/client.rs
-    pub(crate) fn config(self) -> Result<tokio_rustls::rustls::ClientConfig, GremlinError> {
-        let mut cert_store = rustls::RootCertStore::empty();
+impl From<&Path> for TlsSource {
+    fn from(path: &Path) -> Self {
+        TlsSource::File(path.to_path_buf())
+    }
+}
 
-        if let Some(ca_file) = self.authority {
-            let fd = std::fs::File::open(ca_file)?;
-            let mut bufd = BufReader::new(fd);
-            let certs = rustls_pemfile::certs(&mut bufd).flatten(); //.collect::<Vec<_>>();
+/// Which certificate authorities are trusted besides the ones added with
+/// [`TlsOptionsBuilder::authority`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrustRoots {
+    /// The bundled Mozilla roots, unless authorities were added.
+    #[default]
+    Auto,
+    /// The bundled Mozilla roots.
+    WebPki,
+    /// The operating system's certificate store. Needs the `native-roots` feature.
+    Native,
+    /// No others.
+    AuthoritiesOnly,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TlsOptions {
+    pub(crate) authorities: Vec<TlsSource>,
+    pub(crate) roots: TrustRoots,
+    pub(crate) private_key: Option<TlsSource>,
+    pub(crate) auth_certs: Option<TlsSource>,
+    pub(crate) accept_invalid_certs: bool,
+    pub(crate) server_name: Option<String>,
+    pub(crate) verifier: Option<Arc<dyn ServerCertVerifier>>,
+}
+
+impl TlsOptions {
+    pub fn builder() -> TlsOptionsBuilder {
+        TlsOptionsBuilder(Self::default())
+    }
 
-            cert_store.add_parsable_certificates(certs);
+    pub(crate) fn config(&self) -> GremlinResult<rustls::ClientConfig> {
+        // `ClientConfig::builder()` panics when no process-wide provider is installed and the
+        // enabled rustls features don't single one out.
+        let provider = CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()));
+        let builder = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()?;
+        let builder = if let Some(verifier) = &self.verifier {
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(verifier.clone())
+        } else if self.accept_invalid_certs {
+            let verifier = tls::AcceptAnyCertificate::new(builder.crypto_provider().clone());
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(verifier))
         } else {
-            cert_store.extend(TLS_SERVER_ROOTS.iter().cloned());
-        }
+            builder.with_root_certificates(self.root_store()?)
+        };
 
-        let base_config =
-            tokio_rustls::rustls::ClientConfig::builder().with_root_certificates(cert_store);
-        match (&self.private_key, &self.auth_certs) {
-            (None, None) => Ok(base_config.with_no_client_auth()),
-            (Some(key_file), Some(certs_file)) => {
-                let certs = CertificateDer::pem_file_iter(certs_file)?
-                    .flat_map(|result| {
-                        if let Err(e) = &result {
-                            tracing::warn!("{}", e);
-                        }
-                        result
-                    })
-                    .collect::<Vec<_>>();
-                let private_key = PrivateKeyDer::from_pem_file(key_file)?;
-                let config = base_config.with_client_auth_cert(certs, private_key)?;
-
-                Ok(config)
+        match (&self.auth_certs, &self.private_key) {
+            (None, None) => Ok(builder.with_no_client_auth()),
+            (Some(certs), Some(key)) => {
+                Ok(builder
+                    .with_client_auth_cert(tls::certificates(certs)?, tls::private_key(key)?)?)
             }
-            (None, Some(_)) => {
-                tracing::warn!("The certificate file is missing.");
-                panic!();
+            (Some(_), None) => Err(GremlinError::Generic(
+                "TLS client authentication needs a private key besides the certificates".into(),
+            )),
+            (None, Some(_)) => Err(GremlinError::Generic(
+                "TLS client authentication needs certificates besides the private key".into(),
+            )),
+        }
+    }
+
+    fn root_store(&self) -> GremlinResult<RootCertStore> {
+        let mut store = RootCertStore::empty();
+        for authority in &self.authorities {
+            for certificate in tls::certificates(authority)? {
+                store.add(certificate)?;
             }
-            (Some(_), None) => {
-                tracing::warn!("The private key file is missing.");
-                panic!();
+        }
+
+        match self.roots {
+            TrustRoots::Auto if !self.authorities.is_empty() => {}
+            TrustRoots::Auto | TrustRoots::WebPki => {
+                store.extend(TLS_SERVER_ROOTS.iter().cloned());
             }
+            TrustRoots::Native => tls::add_native_roots(&mut store)?,
+            TrustRoots::AuthoritiesOnly => {}
         }
+        Ok(store)
+    }
+}
+
+pub struct TlsOptionsBuilder(TlsOptions);
+
+impl TlsOptionsBuilder {
+    pub fn build(self) -> TlsOptions {
+        self.0
+    }
+
+    /// Trusts the certificate authorities in `source`, which replace the bundled roots unless
+    /// [`roots`](Self::roots) says otherwise. May be called repeatedly.
+    pub fn authority<S: Into<TlsSource>>(mut self, source: S) -> Self {
+        self.0.authorities.push(source.into());
+        self
+    }
+
+    pub fn roots(mut self, roots: TrustRoots) -> Self {
+        self.0.roots = roots;
+        self
+    }
+
+    /// Authenticates the client with a certificate chain and its private key.
+    pub fn client_auth<C, K>(mut self, certs: C, key: K) -> Self
+    where
+        C: Into<TlsSource>,
+        K: Into<TlsSource>,
+    {
+        self.0.auth_certs = Some(certs.into());
+        self.0.private_key = Some(key.into());
+        self
+    }
+
+    pub fn auth_certs<S: Into<TlsSource>>(mut self, source: S) -> Self {
+        self.0.auth_certs = Some(source.into());
+        self
+    }
+
+    pub fn private_key<S: Into<TlsSource>>(mut self, source: S) -> Self {
+        self.0.private_key = Some(source.into());
+        self
+    }
+
+    /// Accepts any server certificate, including expired, self-signed and mismatched ones.
+    /// The connection is still encrypted but no longer authenticated, so anyone in the path can
+    /// intercept it. Only meant for development.
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.0.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// The name sent with SNI and checked against the server certificate, when it differs
+    /// from the host connected to, e.g. for clusters reached by IP address.
+    pub fn server_name<T: Into<String>>(mut self, server_name: T) -> Self {
+        self.0.server_name = Some(server_name.into());
+        self
+    }
+
+    /// Verifies server certificates with `verifier` instead, e.g. to pin a certificate. Takes
+    /// precedence over the trusted roots and [`danger_accept_invalid_certs`].
+    ///
+    /// [`danger_accept_invalid_certs`]: Self::danger_accept_invalid_certs
+    pub fn verifier(mut self, verifier: Arc<dyn ServerCertVerifier>) -> Self {
+        self.0.verifier = Some(verifier);
+        self
     }
 }
 
@@ -199,6 +312,20 @@ impl<_SD: GraphSON> ConnectionOptionsBuilder<_SD> {
         self
     }
 
+    /// How long opening a connection may take, trying every address the host resolves to.
+    /// `None` leaves it to the operating system. Defaults to 10 seconds.
+    pub fn connect_timeout(mut self, connect_timeout: Option<Duration>) -> Self {
+        self.0.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Sends TCP keepalive probes once a connection has been idle this long, so connections
+    /// dropped by the network are noticed. `None`, the default, leaves keepalive off.
+    pub fn tcp_keepalive(mut self, tcp_keepalive: Option<Duration>) -> Self {
+        self.0.tcp_keepalive = tcp_keepalive;
+        self
+    }
+
     pub fn build(self) -> ConnectionOptions<_SD> {
         self.0
     }
@@ -221,11 +348,93 @@ impl<_SD: GraphSON> ConnectionOptionsBuilder<_SD> {
         self
     }
 
+    /// Connects through a proxy rather than to the server directly.
+    pub fn proxy(mut self, options: ProxyOptions) -> Self {
+        self.0.proxy = Some(options);
+        self
+    }
+
     pub fn websocket_options(mut self, options: WebSocketOptions) -> Self {
         self.0.websocket_options = Some(options);
         self
     }
 
+    /// How many responses of a request may wait for its consumer. The connection never waits
+    /// for a slow consumer, as that would stall every other request sharing it. With `None`,
+    /// the default, nothing is bounded: unread responses are queued in memory without limit,
+    /// so set a limit when results may be read slowly or not at all. With a limit, a request
+    /// whose consumer falls further behind fails with an error.
+    pub fn result_buffer_limit(mut self, result_buffer_limit: Option<usize>) -> Self {
+        self.0.result_buffer_limit = result_buffer_limit;
+        self
+    }
+
+    /// How many results the server sends per response, passed as the `batchSize` request
+    /// argument. `None` leaves it to the server's `resultIterationBatchSize`, 64 by default.
+    pub fn batch_size(mut self, batch_size: Option<i32>) -> Self {
+        self.0.batch_size = batch_size;
+        self
+    }
+
+    /// The path of the WebSocket endpoint. Defaults to `/gremlin`.
+    pub fn path<T>(mut self, path: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let path = path.into();
+        self.0.path = if path.starts_with('/') {
+            path
+        } else {
+            format!("/{}", path)
+        };
+        self
+    }
+
+    /// Adds a query parameter to the WebSocket URL. May be called repeatedly.
+    pub fn query_param(mut self, name: &str, value: &str) -> Self {
+        self.0.query.push((String::from(name), String::from(value)));
+        self
+    }
+
+    /// Adds a header to the WebSocket handshake, replacing an earlier one with the same name.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.0
+            .headers
+            .push((String::from(name), String::from(value)));
+        self
+    }
+
+    /// Computes headers each time a connection is opened, e.g. a short-lived token or a
+    /// request signature, given the WebSocket URL. They are added after the ones set with
+    /// [`header`](Self::header), replacing those with the same name. An error fails the
+    /// connection attempt.
+    pub fn header_provider<F>(mut self, provider: F) -> Self
+    where
+        F: Fn(&str) -> GremlinResult<Vec<(String, String)>> + Send + Sync + 'static,
+    {
+        self.0.header_provider = Some(HeaderProvider(Arc::new(provider)));
+        self
+    }
+
+    /// Requests a WebSocket subprotocol, which the server has to agree to.
+    pub fn subprotocol<T>(mut self, subprotocol: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.0.subprotocol = Some(subprotocol.into());
+        self
+    }
+
+    /// The `User-Agent` sent with the handshake. Defaults to `gremlin-client/` and the crate
+    /// version.
+    pub fn user_agent<T>(mut self, user_agent: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.0.user_agent = user_agent.into();
+        self
+    }
+
     pub fn serde<SD: GraphSON>(self, _: SD) -> ConnectionOptionsBuilder<SD> {
         let cloned = ConnectionOptions {
             serde: PhantomData::<SD>,
@@ -243,14 +452,42 @@ pub struct ConnectionOptions<SD: GraphSON> {
     pub(crate) pool_size: u32,
     pub(crate) pool_healthcheck_interval: Option<Duration>,
     pub(crate) pool_get_connection_timeout: Option<Duration>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) tcp_keepalive: Option<Duration>,
     pub(crate) credentials: Option<Credentials>,
     pub(crate) ssl: bool,
     pub(crate) tls_options: Option<TlsOptions>,
+    pub(crate) proxy: Option<ProxyOptions>,
     pub(crate) serde: PhantomData<SD>,
     pub(crate) websocket_options: Option<WebSocketOptions>,
+    pub(crate) result_buffer_limit: Option<usize>,
+    pub(crate) batch_size: Option<i32>,
+    pub(crate) path: String,
+    pub(crate) query: Vec<(String, String)>,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) header_provider: Option<HeaderProvider>,
+    pub(crate) subprotocol: Option<String>,
+    pub(crate) user_agent: String,
 }
 
-#[derive(Clone, Debug)]
+type HeaderFn = dyn Fn(&str) -> GremlinResult<Vec<(String, String)>> + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct HeaderProvider(Arc<HeaderFn>);
+
+impl HeaderProvider {
+    pub(crate) fn headers(&self, url: &str) -> GremlinResult<Vec<(String, String)>> {
+        (self.0)(url)
+    }
+}
+
+impl fmt::Debug for HeaderProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HeaderProvider")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct Credentials {
     pub(crate) username: String,
     pub(crate) password: String,
@@ -295,8 +532,6 @@ impl From<&WebSocketOptions> for tungstenite::protocol::WebSocketConfig {
     }
 }
 
-// impl From<TlsOptions> for std::sync::Arc<>
-
 pub struct WebSocketOptionsBuilder(WebSocketOptions);
 
 impl WebSocketOptionsBuilder {
@@ -323,11 +558,22 @@ impl<SD: GraphSON> Default for ConnectionOptions<SD> {
             pool_size: 10,
             pool_get_connection_timeout: Some(Duration::from_secs(30)),
             pool_healthcheck_interval: None,
+            connect_timeout: Some(Duration::from_secs(10)),
+            tcp_keepalive: None,
             credentials: None,
             ssl: false,
             tls_options: None,
+            proxy: None,
             serde: PhantomData::<SD>,
             websocket_options: None,
+            result_buffer_limit: None,
+            batch_size: None,
+            path: String::from("/gremlin"),
+            query: vec![],
+            headers: vec![],
+            header_provider: None,
+            subprotocol: None,
+            user_agent: format!("gremlin-client/{}", env!("CARGO_PKG_VERSION")),
         }
     }
 }
@@ -341,6 +587,15 @@ impl ConnectionOptions<()> {
 impl<SD: GraphSON> ConnectionOptions<SD> {
     pub fn websocket_url(&self) -> String {
         let protocol = if self.ssl { "wss" } else { "ws" };
-        format!("{}://{}:{}/gremlin", protocol, self.host, self.port)
+        let mut url = format!("{}://{}:{}{}", protocol, self.host, self.port, self.path);
+        if !self.query.is_empty() {
+            url.push('?');
+            url.push_str(
+                &url::form_urlencoded::Serializer::new(String::new())
+                    .extend_pairs(&self.query)
+                    .finish(),
+            );
+        }
+        url
     }
 }
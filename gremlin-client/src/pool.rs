@@ -1,7 +1,7 @@
 use mobc::Manager;
 
 use crate::connection::Conn;
-use crate::error::GremlinError;
+use crate::error::{GremlinError, ServerError};
 use crate::options::ConnectionOptions;
 use crate::prelude::{GValue, GraphSON};
 use base64::prelude::{Engine, BASE64_STANDARD};
@@ -93,20 +93,20 @@ impl<SD: GraphSON> Manager for GremlinConnectionManager<SD> {
                         // 401 is actually a username/password incorrect error, but if not
                         // not returned as okay, the pool loops infinitely trying
                         // to authenticate.
-                        _ => Err(GremlinError::Request((
-                            response.status.code,
-                            response.status.message,
+                        _ => Err(GremlinError::Server(ServerError::new::<SD>(
+                            response.request_id,
+                            response.status,
                         ))),
                     }
                 }
-                None => Err(GremlinError::Request((
-                    response.status.code,
-                    response.status.message,
+                None => Err(GremlinError::Server(ServerError::new::<SD>(
+                    response.request_id,
+                    response.status,
                 ))),
             },
-            _ => Err(GremlinError::Request((
-                response.status.code,
-                response.status.message,
+            _ => Err(GremlinError::Server(ServerError::new::<SD>(
+                response.request_id,
+                response.status,
             ))),
         }
     }
@@ -121,34 +121,31 @@ mod tests {
 
     use super::GremlinConnectionManager;
     use crate::prelude::ConnectionOptions;
+    use crate::runtime;
 
     use mobc::Pool;
     use std::time::Duration;
 
-    use tokio::task;
+    #[test]
+    fn it_should_create_a_connection_pool() {
+        runtime::block_on(async {
+            let manager = GremlinConnectionManager::<()>::new(ConnectionOptions::default());
 
-    #[tokio::test]
-    #[allow(unused_must_use)]
-    async fn it_should_create_a_connection_pool() {
-        let manager = GremlinConnectionManager::<()>::new(ConnectionOptions::default());
+            let pool = Pool::builder().max_open(16).build(manager);
 
-        let pool = Pool::builder().max_open(16).build(manager);
+            let conn = pool.get().await.expect("Failed to get the connection");
 
-        let conn = pool.get().await.expect("Failed to get the connection");
+            pool.state().await;
 
-        pool.state().await;
+            assert_eq!(1, pool.state().await.connections);
 
-        assert_eq!(1, pool.state().await.connections);
+            assert_eq!(0, pool.state().await.idle);
 
-        assert_eq!(0, pool.state().await.idle);
+            drop(conn);
 
-        drop(conn);
+            runtime::sleep(Duration::from_millis(200)).await;
 
-        task::spawn_blocking(move || {
-            std::thread::sleep(Duration::from_millis(200));
+            assert_eq!(1, pool.state().await.idle);
         })
-        .await;
-
-        assert_eq!(1, pool.state().await.idle);
     }
 }
@@ -1,16 +1,23 @@
+use super::step::CallStep;
+use super::step::ConcatStep;
+use super::step::FailStep;
+use super::step::FilterStep;
 use super::step::HasStep;
 use super::step::LoopsStep;
+use super::step::MathStep;
 use super::step::MergeEdgeStep;
 use super::step::MergeVertexStep;
 use super::step::NotStep;
 use super::step::OrStep;
+use super::step::PageRankStep;
 use super::step::RepeatStep;
 use super::step::SelectStep;
+use super::step::SubstringStep;
 use super::step::UntilStep;
 use super::step::WhereStep;
 use super::TraversalBuilder;
 use crate::prelude::GValue;
-use crate::structure::{Either2, GIDs, IntoPredicate, Labels, T};
+use crate::structure::{Either2, GIDs, IntoPredicate, Labels, DT, T};
 
 pub struct AnonymousTraversalSource {
     traversal: TraversalBuilder,
@@ -80,6 +87,61 @@ impl AnonymousTraversalSource {
         self.traversal.clone().v(ids)
     }
 
+    pub fn e<T>(&self, ids: T) -> TraversalBuilder
+    where
+        T: Into<GIDs>,
+    {
+        self.traversal.clone().e(ids)
+    }
+
+    pub fn inject<A>(&self, injection: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().inject(injection)
+    }
+
+    pub fn call<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CallStep>,
+    {
+        self.traversal.clone().call(step)
+    }
+
+    pub fn fail<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<FailStep>,
+    {
+        self.traversal.clone().fail(step)
+    }
+
+    pub fn element(&self) -> TraversalBuilder {
+        self.traversal.clone().element()
+    }
+
+    pub fn index(&self) -> TraversalBuilder {
+        self.traversal.clone().index()
+    }
+
+    pub fn page_rank<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<PageRankStep>,
+    {
+        self.traversal.clone().page_rank(step)
+    }
+
+    pub fn peer_pressure(&self) -> TraversalBuilder {
+        self.traversal.clone().peer_pressure()
+    }
+
+    pub fn connected_component(&self) -> TraversalBuilder {
+        self.traversal.clone().connected_component()
+    }
+
+    pub fn shortest_path(&self) -> TraversalBuilder {
+        self.traversal.clone().shortest_path()
+    }
+
     pub fn add_e<A>(&self, label: A) -> TraversalBuilder
     where
         A: Into<Labels>,
@@ -175,6 +237,13 @@ impl AnonymousTraversalSource {
         self.traversal.clone().has_many(steps)
     }
 
+    pub fn filter<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<FilterStep>,
+    {
+        self.traversal.clone().filter(step)
+    }
+
     pub fn not<A>(&self, step: A) -> TraversalBuilder
     where
         A: Into<NotStep>,
@@ -233,6 +302,17 @@ impl AnonymousTraversalSource {
         self.traversal.clone().cap(step)
     }
 
+    pub fn subgraph<A>(&self, key: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().subgraph(key)
+    }
+
+    pub fn tree(&self) -> TraversalBuilder {
+        self.traversal.clone().tree(None)
+    }
+
     pub fn project<A>(&self, step: A) -> TraversalBuilder
     where
         A: Into<SelectStep>,
@@ -240,6 +320,13 @@ impl AnonymousTraversalSource {
         self.traversal.clone().project(step)
     }
 
+    pub fn math<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<MathStep>,
+    {
+        self.traversal.clone().math(step)
+    }
+
     pub fn constant<A>(&self, value: A) -> TraversalBuilder
     where
         A: Into<GValue>,
@@ -247,6 +334,130 @@ impl AnonymousTraversalSource {
         self.traversal.clone().constant(value)
     }
 
+    pub fn concat<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<ConcatStep>,
+    {
+        self.traversal.clone().concat(step)
+    }
+
+    pub fn as_string(&self) -> TraversalBuilder {
+        self.traversal.clone().as_string()
+    }
+
+    pub fn to_upper(&self) -> TraversalBuilder {
+        self.traversal.clone().to_upper()
+    }
+
+    pub fn to_lower(&self) -> TraversalBuilder {
+        self.traversal.clone().to_lower()
+    }
+
+    pub fn trim(&self) -> TraversalBuilder {
+        self.traversal.clone().trim()
+    }
+
+    pub fn length(&self) -> TraversalBuilder {
+        self.traversal.clone().length()
+    }
+
+    pub fn split<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().split(value)
+    }
+
+    pub fn substring<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<SubstringStep>,
+    {
+        self.traversal.clone().substring(step)
+    }
+
+    pub fn replace<A, B>(&self, old: A, new: B) -> TraversalBuilder
+    where
+        A: Into<String>,
+        B: Into<String>,
+    {
+        self.traversal.clone().replace(old, new)
+    }
+
+    pub fn format<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().format(value)
+    }
+
+    pub fn as_date(&self) -> TraversalBuilder {
+        self.traversal.clone().as_date()
+    }
+
+    pub fn date_add(&self, unit: DT, value: i32) -> TraversalBuilder {
+        self.traversal.clone().date_add(unit, value)
+    }
+
+    pub fn date_diff<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().date_diff(value)
+    }
+
+    pub fn combine<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().combine(value)
+    }
+
+    pub fn merge<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().merge(value)
+    }
+
+    pub fn conjoin<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().conjoin(value)
+    }
+
+    pub fn difference<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().difference(value)
+    }
+
+    pub fn disjunct<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().disjunct(value)
+    }
+
+    pub fn intersect<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().intersect(value)
+    }
+
+    pub fn product<A>(&self, value: A) -> TraversalBuilder
+    where
+        A: Into<GValue>,
+    {
+        self.traversal.clone().product(value)
+    }
+
+    pub fn reverse(&self) -> TraversalBuilder {
+        self.traversal.clone().reverse()
+    }
+
     pub fn until<A>(&self, step: A) -> TraversalBuilder
     where
         A: Into<UntilStep>,
@@ -0,0 +1,28 @@
+use crate::process::traversal::Binding;
+use crate::structure::GValue;
+
+/// Creates [`Binding`]s to use as step arguments.
+///
+/// ```ignore
+/// let b = Bindings::new();
+/// let t = g.v(()).has(("person", "name", b.of("name", "marko"))).out("knows");
+///
+/// let marko = t.to_list().await?;
+/// let josh = t.bind("name", "josh").to_list().await?;
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Bindings;
+
+impl Bindings {
+    pub fn new() -> Bindings {
+        Bindings
+    }
+
+    pub fn of<K, V>(&self, key: K, value: V) -> Binding
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        Binding::new(key, value)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::prelude::{
     traversal::step::*,
     traversal::{Bytecode, Scope},
-    Cardinality, FromGValue, GIDs, GValue, IntoPredicate, Labels, ToGValue, GID,
+    Cardinality, FromGValue, GIDs, GValue, IntoPredicate, Labels, ToGValue, DT, GID,
 };
 
 #[derive(Clone)]
@@ -47,6 +47,86 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn inject<A>(mut self, injection: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("inject"), vec![injection.into()]);
+        self
+    }
+
+    pub fn call<A>(mut self, step: A) -> Self
+    where
+        A: Into<CallStep>,
+    {
+        self.bytecode
+            .add_step(String::from("call"), step.into().into());
+        self
+    }
+
+    pub fn fail<A>(mut self, step: A) -> Self
+    where
+        A: Into<FailStep>,
+    {
+        self.bytecode
+            .add_step(String::from("fail"), step.into().into());
+        self
+    }
+
+    pub fn element(mut self) -> Self {
+        self.bytecode.add_step(String::from("element"), vec![]);
+        self
+    }
+
+    pub fn index(mut self) -> Self {
+        self.bytecode.add_step(String::from("index"), vec![]);
+        self
+    }
+
+    pub fn with_<A>(mut self, step: A) -> Self
+    where
+        A: Into<WithStep>,
+    {
+        self.bytecode
+            .add_step(String::from("with"), step.into().into());
+        self
+    }
+
+    pub fn page_rank<A>(mut self, step: A) -> Self
+    where
+        A: Into<PageRankStep>,
+    {
+        self.bytecode
+            .add_step(String::from("pageRank"), step.into().into());
+        self
+    }
+
+    pub fn peer_pressure(mut self) -> Self {
+        self.bytecode.add_step(String::from("peerPressure"), vec![]);
+        self
+    }
+
+    pub fn connected_component(mut self) -> Self {
+        self.bytecode
+            .add_step(String::from("connectedComponent"), vec![]);
+        self
+    }
+
+    pub fn shortest_path(mut self) -> Self {
+        self.bytecode.add_step(String::from("shortestPath"), vec![]);
+        self
+    }
+
+    pub fn program<A>(mut self, program: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("program"), vec![program.into()]);
+        self
+    }
+
     pub fn has_label<L>(mut self, labels: L) -> Self
     where
         L: Into<Labels>,
@@ -506,6 +586,15 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn filter<A>(mut self, step: A) -> Self
+    where
+        A: Into<FilterStep>,
+    {
+        self.bytecode
+            .add_step(String::from("filter"), step.into().into());
+        self
+    }
+
     pub fn not<A>(mut self, step: A) -> Self
     where
         A: Into<NotStep>,
@@ -566,6 +655,15 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn math<A>(mut self, step: A) -> Self
+    where
+        A: Into<MathStep>,
+    {
+        self.bytecode
+            .add_step(String::from("math"), step.into().into());
+        self
+    }
+
     pub fn repeat<A>(mut self, step: A) -> Self
     where
         A: Into<RepeatStep>,
@@ -696,6 +794,21 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn subgraph<A>(mut self, key: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("subgraph"), vec![key.into().into()]);
+        self
+    }
+
+    pub fn tree(mut self, key: Option<String>) -> Self {
+        let args = key.map(|k| vec![k.into()]).unwrap_or_default();
+        self.bytecode.add_step(String::from("tree"), args);
+        self
+    }
+
     pub fn barrier(mut self) -> Self {
         self.bytecode.add_step(String::from("barrier"), vec![]);
         self
@@ -716,6 +829,167 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn concat<A>(mut self, step: A) -> Self
+    where
+        A: Into<ConcatStep>,
+    {
+        self.bytecode
+            .add_step(String::from("concat"), step.into().into());
+        self
+    }
+
+    pub fn as_string(mut self) -> Self {
+        self.bytecode.add_step(String::from("asString"), vec![]);
+        self
+    }
+
+    pub fn to_upper(mut self) -> Self {
+        self.bytecode.add_step(String::from("toUpper"), vec![]);
+        self
+    }
+
+    pub fn to_lower(mut self) -> Self {
+        self.bytecode.add_step(String::from("toLower"), vec![]);
+        self
+    }
+
+    pub fn trim(mut self) -> Self {
+        self.bytecode.add_step(String::from("trim"), vec![]);
+        self
+    }
+
+    pub fn length(mut self) -> Self {
+        self.bytecode.add_step(String::from("length"), vec![]);
+        self
+    }
+
+    pub fn split<A>(mut self, value: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("split"), vec![value.into().into()]);
+        self
+    }
+
+    pub fn substring<A>(mut self, step: A) -> Self
+    where
+        A: Into<SubstringStep>,
+    {
+        self.bytecode
+            .add_step(String::from("substring"), step.into().into());
+        self
+    }
+
+    pub fn replace<A, B>(mut self, old: A, new: B) -> Self
+    where
+        A: Into<String>,
+        B: Into<String>,
+    {
+        self.bytecode.add_step(
+            String::from("replace"),
+            vec![old.into().into(), new.into().into()],
+        );
+        self
+    }
+
+    pub fn format<A>(mut self, value: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("format"), vec![value.into().into()]);
+        self
+    }
+
+    pub fn as_date(mut self) -> Self {
+        self.bytecode.add_step(String::from("asDate"), vec![]);
+        self
+    }
+
+    pub fn date_add(mut self, unit: DT, value: i32) -> Self {
+        self.bytecode
+            .add_step(String::from("dateAdd"), vec![unit.into(), value.into()]);
+        self
+    }
+
+    pub fn date_diff<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("dateDiff"), vec![value.into()]);
+        self
+    }
+
+    pub fn combine<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("combine"), vec![value.into()]);
+        self
+    }
+
+    pub fn merge<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("merge"), vec![value.into()]);
+        self
+    }
+
+    pub fn conjoin<A>(mut self, value: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("conjoin"), vec![value.into().into()]);
+        self
+    }
+
+    pub fn difference<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("difference"), vec![value.into()]);
+        self
+    }
+
+    pub fn disjunct<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("disjunct"), vec![value.into()]);
+        self
+    }
+
+    pub fn intersect<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("intersect"), vec![value.into()]);
+        self
+    }
+
+    pub fn product<A>(mut self, value: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("product"), vec![value.into()]);
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.bytecode.add_step(String::from("reverse"), vec![]);
+        self
+    }
+
     pub fn emit(mut self) -> Self {
         self.bytecode.add_step(String::from("emit"), vec![]);
         self
@@ -1,4 +1,5 @@
 use crate::prelude::GValue;
+use std::collections::HashMap;
 
 #[derive(PartialEq, Clone)]
 pub struct Bytecode {
@@ -35,6 +36,104 @@ impl Bytecode {
     pub fn sources(&self) -> &Vec<Instruction> {
         &self.source_instructions
     }
+
+    /// The bindings used as arguments, including those in nested traversals and predicates.
+    pub fn bindings(&self) -> HashMap<String, GValue> {
+        let mut bindings = HashMap::new();
+        for arg in self.instructions().flat_map(|i| i.args.iter()) {
+            collect_bindings(arg, &mut bindings);
+        }
+        bindings
+    }
+
+    /// Replaces the value of every binding named `key`, returning whether one was found.
+    pub fn rebind(&mut self, key: &str, value: &GValue) -> bool {
+        let mut found = false;
+        for instruction in self
+            .source_instructions
+            .iter_mut()
+            .chain(self.step_instructions.iter_mut())
+        {
+            for arg in instruction.args.iter_mut() {
+                found |= rebind(arg, key, value);
+            }
+        }
+        found
+    }
+
+    fn instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.source_instructions
+            .iter()
+            .chain(self.step_instructions.iter())
+    }
+}
+
+fn collect_bindings(value: &GValue, bindings: &mut HashMap<String, GValue>) {
+    match value {
+        GValue::Binding(binding) => {
+            bindings.insert(binding.key.clone(), (*binding.value).clone());
+        }
+        GValue::Bytecode(bytecode) => bindings.extend(bytecode.bindings()),
+        GValue::P(p) => collect_bindings(&p.value, bindings),
+        GValue::TextP(p) => collect_bindings(&p.value, bindings),
+        GValue::List(list) => list.iter().for_each(|v| collect_bindings(v, bindings)),
+        GValue::Set(set) => set.iter().for_each(|v| collect_bindings(v, bindings)),
+        GValue::Map(map) => map.iter().for_each(|(_, v)| collect_bindings(v, bindings)),
+        _ => {}
+    }
+}
+
+fn rebind(arg: &mut GValue, key: &str, value: &GValue) -> bool {
+    match arg {
+        GValue::Binding(binding) if binding.key == key => {
+            binding.value = Box::new(value.clone());
+            true
+        }
+        GValue::Bytecode(bytecode) => bytecode.rebind(key, value),
+        GValue::P(p) => rebind(&mut p.value, key, value),
+        GValue::TextP(p) => rebind(&mut p.value, key, value),
+        GValue::List(list) => list
+            .0
+            .iter_mut()
+            .fold(false, |found, v| rebind(v, key, value) | found),
+        GValue::Set(set) => set
+            .0
+            .iter_mut()
+            .fold(false, |found, v| rebind(v, key, value) | found),
+        GValue::Map(map) => map
+            .values_mut()
+            .fold(false, |found, v| rebind(v, key, value) | found),
+        _ => false,
+    }
+}
+
+/// A named argument: the server receives both the name and the value, so traversals that differ
+/// only in bound values share the same cached plan.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Binding {
+    key: String,
+    value: Box<GValue>,
+}
+
+impl Binding {
+    pub fn new<K, V>(key: K, value: V) -> Binding
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        Binding {
+            key: key.into(),
+            value: Box::new(value.into()),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &GValue {
+        &self.value
+    }
 }
 
 lazy_static! {
@@ -0,0 +1,177 @@
+//! Graph-computer (OLAP) configuration for
+//! [`with_computer`](crate::process::traversal::GraphTraversalSource::with_computer) and the
+//! option keys of the vertex-program steps, to be passed to `with_()`.
+
+use crate::process::traversal::strategies::TraversalStrategy;
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::GValue;
+
+const VERTEX_PROGRAM_STRATEGY: &str =
+    "org.apache.tinkerpop.gremlin.process.computer.traversal.strategy.decoration.VertexProgramStrategy";
+
+/// What the graph computer writes back to the graph.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Persist {
+    Nothing,
+    VertexProperties,
+    Edges,
+}
+
+impl Persist {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Persist::Nothing => "NOTHING",
+            Persist::VertexProperties => "VERTEX_PROPERTIES",
+            Persist::Edges => "EDGES",
+        }
+    }
+}
+
+/// Whether results are written to the original graph or to a new one.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ResultGraph {
+    Original,
+    New,
+}
+
+impl ResultGraph {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ResultGraph::Original => "ORIGINAL",
+            ResultGraph::New => "NEW",
+        }
+    }
+}
+
+/// The graph computer a traversal is submitted to. Sent as a `VertexProgramStrategy`.
+#[derive(Default, Clone)]
+pub struct Computer {
+    graph_computer: Option<String>,
+    workers: Option<i32>,
+    persist: Option<Persist>,
+    result: Option<ResultGraph>,
+    vertices: Option<TraversalBuilder>,
+    edges: Option<TraversalBuilder>,
+    configuration: Vec<(String, GValue)>,
+}
+
+impl Computer {
+    /// The server's default graph computer.
+    pub fn new() -> Computer {
+        Default::default()
+    }
+
+    /// The fully qualified class name of the computer, e.g.
+    /// `org.apache.tinkerpop.gremlin.spark.process.computer.SparkGraphComputer`.
+    pub fn graph_computer<C>(mut self, class: C) -> Self
+    where
+        C: Into<String>,
+    {
+        self.graph_computer = Some(class.into());
+        self
+    }
+
+    pub fn workers(mut self, workers: i32) -> Self {
+        self.workers = Some(workers);
+        self
+    }
+
+    pub fn persist(mut self, persist: Persist) -> Self {
+        self.persist = Some(persist);
+        self
+    }
+
+    pub fn result(mut self, result: ResultGraph) -> Self {
+        self.result = Some(result);
+        self
+    }
+
+    /// Restricts the vertices loaded by the computer.
+    pub fn vertices(mut self, filter: TraversalBuilder) -> Self {
+        self.vertices = Some(filter);
+        self
+    }
+
+    /// Restricts the edges loaded by the computer.
+    pub fn edges(mut self, filter: TraversalBuilder) -> Self {
+        self.edges = Some(filter);
+        self
+    }
+
+    /// Extra provider-specific configuration, e.g. Spark properties.
+    pub fn configure<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.configuration.push((key.into(), value.into()));
+        self
+    }
+}
+
+impl From<Computer> for TraversalStrategy {
+    fn from(computer: Computer) -> Self {
+        let mut result = TraversalStrategy::new(VERTEX_PROGRAM_STRATEGY);
+        if let Some(graph_computer) = computer.graph_computer {
+            result = result.config("graphComputer", graph_computer);
+        }
+        if let Some(workers) = computer.workers {
+            result = result.config("workers", workers);
+        }
+        if let Some(persist) = computer.persist {
+            result = result.config("persist", persist.as_str());
+        }
+        if let Some(graph) = computer.result {
+            result = result.config("result", graph.as_str());
+        }
+        if let Some(vertices) = computer.vertices {
+            result = result.config("vertices", vertices);
+        }
+        if let Some(edges) = computer.edges {
+            result = result.config("edges", edges);
+        }
+        computer
+            .configuration
+            .into_iter()
+            .fold(result, |result, (key, value)| result.config(key, value))
+    }
+}
+
+/// Options of `pageRank()`.
+pub struct PageRank;
+
+impl PageRank {
+    pub const PROPERTY_NAME: &'static str = "~tinkerpop.pageRank.propertyName";
+    pub const EDGES: &'static str = "~tinkerpop.pageRank.edges";
+    pub const TIMES: &'static str = "~tinkerpop.pageRank.times";
+}
+
+/// Options of `peerPressure()`.
+pub struct PeerPressure;
+
+impl PeerPressure {
+    pub const PROPERTY_NAME: &'static str = "~tinkerpop.peerPressure.propertyName";
+    pub const EDGES: &'static str = "~tinkerpop.peerPressure.edges";
+    pub const TIMES: &'static str = "~tinkerpop.peerPressure.times";
+}
+
+/// Options of `connectedComponent()`.
+pub struct ConnectedComponent;
+
+impl ConnectedComponent {
+    /// The property holding the component id when no `PROPERTY_NAME` is given.
+    pub const COMPONENT: &'static str = "gremlin.connectedComponentVertexProgram.component";
+    pub const PROPERTY_NAME: &'static str = "~tinkerpop.connectedComponent.propertyName";
+    pub const EDGES: &'static str = "~tinkerpop.connectedComponent.edges";
+}
+
+/// Options of `shortestPath()`.
+pub struct ShortestPath;
+
+impl ShortestPath {
+    pub const TARGET: &'static str = "~tinkerpop.shortestPath.target";
+    pub const EDGES: &'static str = "~tinkerpop.shortestPath.edges";
+    pub const DISTANCE: &'static str = "~tinkerpop.shortestPath.distance";
+    pub const MAX_DISTANCE: &'static str = "~tinkerpop.shortestPath.maxDistance";
+    pub const INCLUDE_EDGES: &'static str = "~tinkerpop.shortestPath.includeEdges";
+}
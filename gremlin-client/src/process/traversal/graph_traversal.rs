@@ -1,8 +1,9 @@
 use crate::prelude::{
     traversal::{remote::Terminator, step::*, Bytecode, Scope, TraversalBuilder, WRITE_OPERATORS},
     Cardinality, Edge, FromGValue, GIDs, GProperty, GValue, IntoPredicate, Labels, List, Map, Null,
-    Path, Vertex,
+    Path, Set, Tree, Vertex, DT,
 };
+use chrono::{DateTime, Utc};
 use std::marker::PhantomData;
 
 #[derive(Clone)]
@@ -34,6 +35,21 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         &self.builder.bytecode
     }
 
+    /// A copy of this traversal with a new value for the binding `key`, leaving the steps as
+    /// they are. Unknown keys leave the traversal unchanged.
+    pub fn bind<K, V>(&self, key: K, value: V) -> Self
+    where
+        K: AsRef<str>,
+        V: Into<GValue>,
+    {
+        let mut traversal = self.clone();
+        traversal
+            .builder
+            .bytecode
+            .rebind(key.as_ref(), &value.into());
+        traversal
+    }
+
     pub fn has_label<L>(mut self, labels: L) -> Self
     where
         L: Into<Labels>,
@@ -296,9 +312,29 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self.terminator.to_list(self)
     }
 
+    /// The first result, without reading the rest of them.
     pub fn next(&self) -> T::Next {
         self.terminator.next(self)
     }
+
+    /// Like [`next`](Self::next), with a `limit(1)` appended so the server stops producing
+    /// results after the first one.
+    pub fn first(&self) -> T::Next {
+        let traversal: GraphTraversal<S, E, T> =
+            GraphTraversal::new(self.terminator.clone(), self.builder.clone().limit(1));
+        self.terminator.next(&traversal)
+    }
+
+    /// The only result, failing if there are none or more than one.
+    pub fn one(&self) -> T::One {
+        self.terminator.one(self)
+    }
+
+    /// Up to `n` results, without reading the rest of them.
+    pub fn try_next_n(&self, n: usize) -> T::NextN {
+        self.terminator.try_next_n(self, n)
+    }
+
     pub fn has_next(&self) -> T::HasNext {
         self.terminator.has_next(self)
     }
@@ -307,6 +343,20 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self.terminator.iter(self)
     }
 
+    pub fn profile(&self) -> T::Profile {
+        self.terminator.profile(self)
+    }
+
+    pub fn explain(&self) -> T::Explain {
+        self.terminator.explain(self)
+    }
+
+    /// Like [`iter`](Self::iter), but yields `(value, bulk)` pairs instead of repeating
+    /// bulked values.
+    pub fn iter_traversers(&self) -> T::Traversers {
+        self.terminator.iter_traversers(self)
+    }
+
     pub fn from<A>(mut self, target: A) -> Self
     where
         A: Into<FromStep>,
@@ -524,6 +574,14 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self
     }
 
+    pub fn filter<A>(mut self, step: A) -> Self
+    where
+        A: Into<FilterStep>,
+    {
+        self.builder = self.builder.filter(step);
+        self
+    }
+
     pub fn not<A>(mut self, step: A) -> Self
     where
         A: Into<NotStep>,
@@ -571,6 +629,15 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self
     }
 
+    pub fn math<A>(mut self, step: A) -> GraphTraversal<S, f64, T>
+    where
+        A: Into<MathStep>,
+        T: Terminator<f64>,
+    {
+        self.builder = self.builder.math(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn project<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
     where
         A: Into<SelectStep>,
@@ -580,11 +647,105 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         GraphTraversal::new(self.terminator, self.builder)
     }
 
-    pub fn v<VT>(mut self, ids: VT) -> Self
+    pub fn v<VT>(mut self, ids: VT) -> GraphTraversal<S, Vertex, T>
     where
         VT: Into<GIDs>,
+        T: Terminator<Vertex>,
     {
         self.builder = self.builder.v(ids);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn e<ET>(mut self, ids: ET) -> GraphTraversal<S, Edge, T>
+    where
+        ET: Into<GIDs>,
+        T: Terminator<Edge>,
+    {
+        self.builder = self.builder.e(ids);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn inject<A>(mut self, injection: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.builder = self.builder.inject(injection);
+        self
+    }
+
+    pub fn call<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CallStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.call(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn fail<A>(mut self, step: A) -> Self
+    where
+        A: Into<FailStep>,
+    {
+        self.builder = self.builder.fail(step);
+        self
+    }
+
+    pub fn element(mut self) -> GraphTraversal<S, GValue, T>
+    where
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.element();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn index(mut self) -> GraphTraversal<S, List, T>
+    where
+        T: Terminator<List>,
+    {
+        self.builder = self.builder.index();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn with_<A>(mut self, step: A) -> Self
+    where
+        A: Into<WithStep>,
+    {
+        self.builder = self.builder.with_(step);
+        self
+    }
+
+    pub fn page_rank<A>(mut self, step: A) -> Self
+    where
+        A: Into<PageRankStep>,
+    {
+        self.builder = self.builder.page_rank(step);
+        self
+    }
+
+    pub fn peer_pressure(mut self) -> Self {
+        self.builder = self.builder.peer_pressure();
+        self
+    }
+
+    pub fn connected_component(mut self) -> Self {
+        self.builder = self.builder.connected_component();
+        self
+    }
+
+    pub fn shortest_path(mut self) -> GraphTraversal<S, Path, T>
+    where
+        T: Terminator<Path>,
+    {
+        self.builder = self.builder.shortest_path();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    /// Runs a custom vertex program. The server must be able to deserialize `program`.
+    pub fn program<A>(mut self, program: A) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.builder = self.builder.program(program);
         self
     }
 
@@ -696,6 +857,41 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self
     }
 
+    /// Like [`cap`](Self::cap) but emits the side effect as `C`, e.g. a
+    /// [`TinkerGraph`](crate::structure::TinkerGraph) collected by [`subgraph`](Self::subgraph).
+    pub fn cap_as<C>(mut self, step: &'static str) -> GraphTraversal<S, C, T>
+    where
+        C: FromGValue,
+        T: Terminator<C>,
+    {
+        self.builder = self.builder.cap(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn subgraph<A>(mut self, key: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.builder = self.builder.subgraph(key);
+        self
+    }
+
+    pub fn tree(mut self) -> GraphTraversal<S, Tree, T>
+    where
+        T: Terminator<Tree>,
+    {
+        self.builder = self.builder.tree(None);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn tree_as<A>(mut self, key: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.builder = self.builder.tree(Some(key.into()));
+        self
+    }
+
     pub fn barrier(mut self) -> Self {
         self.builder = self.builder.barrier();
         self
@@ -722,6 +918,188 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self
     }
 
+    pub fn concat<A>(mut self, step: A) -> GraphTraversal<S, String, T>
+    where
+        A: Into<ConcatStep>,
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.concat(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn as_string(mut self) -> GraphTraversal<S, String, T>
+    where
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.as_string();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn to_upper(mut self) -> GraphTraversal<S, String, T>
+    where
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.to_upper();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn to_lower(mut self) -> GraphTraversal<S, String, T>
+    where
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.to_lower();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn trim(mut self) -> GraphTraversal<S, String, T>
+    where
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.trim();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn length(mut self) -> GraphTraversal<S, i32, T>
+    where
+        T: Terminator<i32>,
+    {
+        self.builder = self.builder.length();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn split<A>(mut self, value: A) -> GraphTraversal<S, List, T>
+    where
+        A: Into<String>,
+        T: Terminator<List>,
+    {
+        self.builder = self.builder.split(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn substring<A>(mut self, step: A) -> GraphTraversal<S, String, T>
+    where
+        A: Into<SubstringStep>,
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.substring(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn replace<A, B>(mut self, old: A, new: B) -> GraphTraversal<S, String, T>
+    where
+        A: Into<String>,
+        B: Into<String>,
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.replace(old, new);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn format<A>(mut self, value: A) -> GraphTraversal<S, String, T>
+    where
+        A: Into<String>,
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.format(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn as_date(mut self) -> GraphTraversal<S, DateTime<Utc>, T>
+    where
+        T: Terminator<DateTime<Utc>>,
+    {
+        self.builder = self.builder.as_date();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn date_add(mut self, unit: DT, value: i32) -> GraphTraversal<S, DateTime<Utc>, T>
+    where
+        T: Terminator<DateTime<Utc>>,
+    {
+        self.builder = self.builder.date_add(unit, value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn date_diff<A>(mut self, value: A) -> GraphTraversal<S, i64, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<i64>,
+    {
+        self.builder = self.builder.date_diff(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn combine<A>(mut self, value: A) -> GraphTraversal<S, List, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<List>,
+    {
+        self.builder = self.builder.combine(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn merge<A>(mut self, value: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.merge(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn conjoin<A>(mut self, value: A) -> GraphTraversal<S, String, T>
+    where
+        A: Into<String>,
+        T: Terminator<String>,
+    {
+        self.builder = self.builder.conjoin(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn difference<A>(mut self, value: A) -> GraphTraversal<S, Set, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<Set>,
+    {
+        self.builder = self.builder.difference(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn disjunct<A>(mut self, value: A) -> GraphTraversal<S, Set, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<Set>,
+    {
+        self.builder = self.builder.disjunct(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn intersect<A>(mut self, value: A) -> GraphTraversal<S, Set, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<Set>,
+    {
+        self.builder = self.builder.intersect(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn product<A>(mut self, value: A) -> GraphTraversal<S, List, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<List>,
+    {
+        self.builder = self.builder.product(value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn reverse(mut self) -> GraphTraversal<S, GValue, T>
+    where
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.reverse();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn emit(mut self) -> Self {
         self.builder = self.builder.emit();
         self
@@ -1,18 +1,25 @@
 use crate::prelude::{
     traversal::remote::{AsyncTerminator, MockTerminator, Terminator},
     traversal::step::*,
-    traversal::{Bytecode, GraphTraversal, TraversalBuilder},
-    Edge, FromGValue, GIDs, GValue, GraphSON, GremlinClient, Labels, ToGValue, Vertex,
+    traversal::computer::Computer,
+    traversal::strategies::TraversalStrategy,
+    traversal::{parse, Bytecode, GraphTraversal, LocalTerminator, TraversalBuilder},
+    Edge, FromGValue, GIDs, GValue, GraphSON, GremlinClient, GremlinResult, Labels, TinkerGraph,
+    ToGValue, Vertex,
 };
 
 #[derive(Clone)]
 pub struct GraphTraversalSource<A: Terminator<GValue>> {
     term: A,
+    bytecode: Bytecode,
 }
 
 impl<A: Terminator<GValue>> GraphTraversalSource<A> {
     pub fn new(terminator: A) -> GraphTraversalSource<A> {
-        GraphTraversalSource { term: terminator }
+        GraphTraversalSource {
+            term: terminator,
+            bytecode: Bytecode::new(),
+        }
     }
 
     pub fn empty() -> GraphTraversalSource<MockTerminator> {
@@ -25,15 +32,67 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
     ) -> GraphTraversalSource<AsyncTerminator<SD>> {
         GraphTraversalSource {
             term: AsyncTerminator::new(client),
+            bytecode: self.bytecode.clone(),
+        }
+    }
+
+    pub fn with_local(&self, graph: TinkerGraph) -> GraphTraversalSource<LocalTerminator> {
+        GraphTraversalSource {
+            term: LocalTerminator::new(graph),
+            bytecode: self.bytecode.clone(),
         }
     }
 
+    /// Source instructions applied to every traversal spawned from this source.
+    pub fn bytecode(&self) -> &Bytecode {
+        &self.bytecode
+    }
+
+    pub fn with_strategies<I>(&self, strategies: I) -> GraphTraversalSource<A>
+    where
+        I: IntoIterator,
+        I::Item: Into<TraversalStrategy>,
+    {
+        let mut source = self.clone();
+
+        source.bytecode.add_source(
+            String::from("withStrategies"),
+            strategies
+                .into_iter()
+                .map(|s| GValue::Strategy(s.into()))
+                .collect(),
+        );
+        source
+    }
+
+    pub fn without_strategies<I>(&self, strategies: I) -> GraphTraversalSource<A>
+    where
+        I: IntoIterator,
+        I::Item: Into<TraversalStrategy>,
+    {
+        let mut source = self.clone();
+
+        source.bytecode.add_source(
+            String::from("withoutStrategies"),
+            strategies
+                .into_iter()
+                .map(|s| GValue::Class(s.into().class().to_string()))
+                .collect(),
+        );
+        source
+    }
+
+    /// Submits traversals spawned from this source to a graph computer (OLAP).
+    pub fn with_computer(&self, computer: Computer) -> GraphTraversalSource<A> {
+        self.with_strategies(vec![computer])
+    }
+
     pub fn v<T>(&self, ids: T) -> GraphTraversal<Vertex, Vertex, A>
     where
         T: Into<GIDs>,
         A: Terminator<Vertex>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(
             String::from("V"),
@@ -48,7 +107,7 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         T: Into<Labels>,
         A: Terminator<Vertex>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(
             String::from("addV"),
@@ -63,7 +122,7 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         T: Into<Labels>,
         A: Terminator<Edge>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(
             String::from("addE"),
@@ -78,7 +137,7 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         T: Into<GIDs>,
         A: Terminator<Edge>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(
             String::from("E"),
@@ -93,7 +152,7 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         T: Into<GValue> + FromGValue,
         A: Terminator<T>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_source(
             String::from("withSideEffect"),
@@ -107,30 +166,55 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         T: Into<GValue> + FromGValue,
         A: Terminator<T>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(String::from("inject"), vec![injection.into()]);
         GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
     }
 
+    pub fn call<C>(&self, step: C) -> GraphTraversal<GValue, GValue, A>
+    where
+        C: Into<CallStep>,
+    {
+        let mut code = self.bytecode.clone();
+
+        code.add_step(String::from("call"), step.into().into());
+        GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
+    }
+
     pub fn merge_v<V>(&self, merge_v: V) -> GraphTraversal<Vertex, Vertex, A>
     where
         V: Into<MergeVertexStep>,
         A: Terminator<Vertex>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(String::from("mergeV"), merge_v.into().into());
 
         GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
     }
 
+    /// Parses a gremlin-lang traversal (e.g. `g.V().out('knows')`) and spawns it from this
+    /// source, so it is submitted as bytecode.
+    pub fn parse(&self, query: &str) -> GremlinResult<GraphTraversal<GValue, GValue, A>> {
+        let parsed = parse(query)?;
+        let mut code = self.bytecode.clone();
+
+        for source in parsed.sources() {
+            code.add_source(source.operator().clone(), source.args().clone());
+        }
+        for step in parsed.steps() {
+            code.add_step(step.operator().clone(), step.args().clone());
+        }
+        Ok(GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code)))
+    }
+
     pub fn merge_e<V>(&self, merge_e: V) -> GraphTraversal<Edge, Edge, A>
     where
         V: Into<MergeEdgeStep>,
         A: Terminator<Edge>,
     {
-        let mut code = Bytecode::new();
+        let mut code = self.bytecode.clone();
 
         code.add_step(String::from("mergeE"), merge_e.into().into());
 
@@ -145,8 +229,13 @@ mod tests {
     use crate::process::traversal::remote::MockTerminator;
 
     use super::GraphTraversalSource;
-    use crate::process::traversal::{Bytecode, Order, Scope, __};
-    use crate::structure::{GValue, P, T};
+    use crate::process::traversal::computer::{Computer, PageRank, Persist, ShortestPath};
+    use crate::process::traversal::strategies::{
+        PartitionStrategy, ReadOnlyStrategy, TraversalStrategy,
+    };
+    use crate::process::traversal::{Binding, Bindings, Bytecode, Order, Scope, __};
+    use crate::structure::{Direction, GKey, GValue, Lambda, TinkerGraph, Tree, Vertex, DT, P, T};
+    use std::collections::HashMap;
 
     fn empty() -> GraphTraversalSource<MockTerminator> {
         GraphTraversalSource::new(MockTerminator {})
@@ -1016,5 +1105,485 @@ mod tests {
         );
     }
 
+    #[test]
+    fn math_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("as"), vec!["a".into()]);
+        code.add_step(String::from("out"), vec!["knows".into()]);
+        code.add_step(String::from("math"), vec!["a + _".into()]);
+        code.add_step(String::from("by"), vec!["age".into()]);
+
+        assert_eq!(
+            &code,
+            g.v(()).as_("a").out("knows").math("a + _").by("age").bytecode()
+        );
+    }
+
+    #[test]
+    fn subgraph_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("E"), vec![]);
+        code.add_step(String::from("hasLabel"), vec!["knows".into()]);
+        code.add_step(String::from("subgraph"), vec!["sg".into()]);
+        code.add_step(String::from("cap"), vec!["sg".into()]);
+
+        assert_eq!(
+            &code,
+            g.e(())
+                .has_label("knows")
+                .subgraph("sg")
+                .cap_as::<TinkerGraph>("sg")
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn tree_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("out"), vec![]);
+        code.add_step(String::from("tree"), vec![]);
+        code.add_step(String::from("by"), vec!["name".into()]);
+
+        assert_eq!(&code, g.v(()).out(()).tree().by("name").bytecode());
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("out"), vec![]);
+        code.add_step(String::from("tree"), vec!["t".into()]);
+        code.add_step(String::from("cap"), vec!["t".into()]);
+
+        assert_eq!(
+            &code,
+            g.v(()).out(()).tree_as("t").cap_as::<Tree>("t").bytecode()
+        );
+    }
+
+    #[test]
+    fn string_steps_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("values"), vec!["name".into()]);
+        code.add_step(String::from("concat"), vec!["-".into(), "x".into()]);
+        code.add_step(String::from("toUpper"), vec![]);
+        code.add_step(String::from("substring"), vec![1.into(), 3.into()]);
+        code.add_step(String::from("replace"), vec!["A".into(), "B".into()]);
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .values("name")
+                .concat(vec!["-", "x"])
+                .to_upper()
+                .substring((1, 3))
+                .replace("A", "B")
+                .bytecode()
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("concat"),
+            vec![__.values("name").bytecode().clone().into()],
+        );
+        code.add_step(String::from("split"), vec![",".into()]);
+
+        assert_eq!(
+            &code,
+            g.v(()).concat(__.values("name")).split(",").bytecode()
+        );
+    }
+
+    #[test]
+    fn date_steps_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("inject"), vec!["2023-08-02T00:00:00Z".into()]);
+        code.add_step(String::from("asDate"), vec![]);
+        code.add_step(String::from("dateAdd"), vec![DT::Day.into(), 7.into()]);
+
+        assert_eq!(
+            &code,
+            g.inject(String::from("2023-08-02T00:00:00Z"))
+                .as_date()
+                .date_add(DT::Day, 7)
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn list_steps_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("values"), vec!["name".into()]);
+        code.add_step(String::from("fold"), vec![]);
+        code.add_step(
+            String::from("intersect"),
+            vec![vec!["marko".into(), "josh".into()].into()],
+        );
+        code.add_step(String::from("conjoin"), vec!["|".into()]);
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .values("name")
+                .fold()
+                .intersect(vec![GValue::from("marko"), GValue::from("josh")])
+                .conjoin("|")
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn call_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("call"), vec![]);
+
+        assert_eq!(&code, g.call(()).bytecode());
+
+        let mut params = HashMap::new();
+        params.insert(GKey::from("search"), GValue::from("mar*"));
+
+        let mut code = Bytecode::new();
+
+        code.add_step(
+            String::from("call"),
+            vec!["tinker.search".into(), params.clone().into()],
+        );
+        code.add_step(String::from("element"), vec![]);
+
+        assert_eq!(
+            &code,
+            g.call(("tinker.search", params.clone())).element().bytecode()
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("call"),
+            vec![
+                "tinker.degree.centrality".into(),
+                params.clone().into(),
+                __.values("direction").bytecode().clone().into(),
+            ],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .call((
+                    "tinker.degree.centrality",
+                    params,
+                    __.values("direction")
+                ))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn fail_index_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("coalesce"),
+            vec![
+                __.out(()).bytecode().clone().into(),
+                __.fail("no neighbours").bytecode().clone().into(),
+            ],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .coalesce::<Vertex, _>([__.out(()), __.fail("no neighbours")])
+                .bytecode()
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("inject"), vec!["a".into()]);
+        code.add_step(String::from("inject"), vec!["b".into()]);
+        code.add_step(String::from("fold"), vec![]);
+        code.add_step(String::from("index"), vec![]);
+
+        assert_eq!(
+            &code,
+            g.inject(String::from("a"))
+                .inject("b")
+                .fold()
+                .index()
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn mid_traversal_e_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![1.into()]);
+        code.add_step(String::from("E"), vec![7.into()]);
+        code.add_step(String::from("V"), vec![]);
+
+        assert_eq!(&code, g.v(1).e(7).v(()).bytecode());
+    }
+
+    #[test]
+    fn merge_e_direction_test() {
+        let g = empty();
+
+        let mut map = HashMap::new();
+        map.insert(GKey::T(T::Label), GValue::from("knows"));
+        map.insert(GKey::Direction(Direction::Out), GValue::from(1));
+        map.insert(GKey::Direction(Direction::In), GValue::from(2));
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("mergeE"), vec![map.into()]);
+
+        assert_eq!(
+            &code,
+            g.merge_e(vec![
+                (GKey::from(T::Label), GValue::from("knows")),
+                (Direction::Out.into(), 1.into()),
+                (Direction::In.into(), 2.into()),
+            ])
+            .bytecode()
+        );
+    }
+
+    #[test]
+    fn with_strategies_test() {
+        let g = empty().with_strategies([PartitionStrategy::new("_partition")
+            .write_partition("a")
+            .read_partition("a")]);
+
+        let mut code = Bytecode::new();
+
+        code.add_source(
+            String::from("withStrategies"),
+            vec![GValue::Strategy(
+                TraversalStrategy::new(
+                    "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration.PartitionStrategy",
+                )
+                .config("partitionKey", "_partition")
+                .config("writePartition", "a")
+                .config("readPartitions", vec![GValue::from("a")]),
+            )],
+        );
+        code.add_step(String::from("V"), vec![]);
+
+        assert_eq!(&code, g.v(()).bytecode());
+
+        let g = g.without_strategies([ReadOnlyStrategy::new()]);
+
+        code.add_source(
+            String::from("withoutStrategies"),
+            vec![GValue::Class(String::from(
+                "org.apache.tinkerpop.gremlin.process.traversal.strategy.verification.ReadOnlyStrategy",
+            ))],
+        );
+
+        assert_eq!(&code, g.v(()).bytecode());
+    }
+
+    #[test]
+    fn with_computer_test() {
+        let g = empty().with_computer(
+            Computer::new()
+                .workers(4)
+                .persist(Persist::VertexProperties)
+                .vertices(__.has_label("person")),
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_source(
+            String::from("withStrategies"),
+            vec![GValue::Strategy(
+                TraversalStrategy::new(
+                    "org.apache.tinkerpop.gremlin.process.computer.traversal.strategy.decoration.VertexProgramStrategy",
+                )
+                .config("workers", 4)
+                .config("persist", "VERTEX_PROPERTIES")
+                .config("vertices", __.has_label("person")),
+            )],
+        );
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("pageRank"), vec![0.85.into()]);
+        code.add_step(
+            String::from("with"),
+            vec![PageRank::PROPERTY_NAME.into(), "rank".into()],
+        );
+        code.add_step(String::from("with"), vec![PageRank::TIMES.into(), 5.into()]);
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .page_rank(0.85)
+                .with_((PageRank::PROPERTY_NAME, "rank"))
+                .with_((PageRank::TIMES, 5))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn shortest_path_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![1.into()]);
+        code.add_step(String::from("shortestPath"), vec![]);
+        code.add_step(
+            String::from("with"),
+            vec![
+                ShortestPath::TARGET.into(),
+                __.has_label("software").bytecode().clone().into(),
+            ],
+        );
+        code.add_step(
+            String::from("with"),
+            vec![ShortestPath::INCLUDE_EDGES.into(), true.into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(1)
+                .shortest_path()
+                .with_((ShortestPath::TARGET, __.has_label("software")))
+                .with_((ShortestPath::INCLUDE_EDGES, true))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        let g = empty().with_strategies(vec![ReadOnlyStrategy::new()]);
+
+        assert_eq!(
+            g.v(1).out("knows").values("name").bytecode(),
+            g.parse("g.V(1).out('knows').values('name')")
+                .unwrap()
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn lambda_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("filter"),
+            vec![Lambda::new("{ it.get().value('age') > 30 }").into()],
+        );
+        code.add_step(String::from("order"), vec![Scope::Global.into()]);
+        code.add_step(
+            String::from("by"),
+            vec![
+                "name".into(),
+                Lambda::new("{ a, b -> a.length() <=> b.length() }")
+                    .arguments(2)
+                    .into(),
+            ],
+        );
+        code.add_step(
+            String::from("map"),
+            vec![Lambda::new("it.get().label()").into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .filter(Lambda::new("{ it.get().value('age') > 30 }"))
+                .order(())
+                .by((
+                    "name",
+                    Lambda::new("{ a, b -> a.length() <=> b.length() }").arguments(2)
+                ))
+                .map(Lambda::new("it.get().label()"))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn bindings_test() {
+        let g = empty();
+        let b = Bindings::new();
+
+        let t = g
+            .v(())
+            .has(("person", "name", b.of("name", "marko")))
+            .where_(__.out("knows").has(("age", P::gt(b.of("age", 30)))));
+
+        let mut code = Bytecode::new();
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("has"),
+            vec![
+                "person".into(),
+                "name".into(),
+                P::eq(Binding::new("name", "marko")).into(),
+            ],
+        );
+        code.add_step(
+            String::from("where"),
+            vec![__
+                .out("knows")
+                .has(("age", P::gt(Binding::new("age", 30))))
+                .bytecode()
+                .clone()
+                .into()],
+        );
+        assert_eq!(&code, t.bytecode());
+
+        let josh = t.bind("name", "josh").bind("age", 20);
+
+        let mut bindings = HashMap::new();
+        bindings.insert(String::from("name"), GValue::from("josh"));
+        bindings.insert(String::from("age"), GValue::from(20));
+        assert_eq!(bindings, josh.bytecode().bindings());
+        assert_eq!(Some(&"marko".into()), t.bytecode().bindings().get("name"));
+
+        let mut properties: HashMap<GKey, GValue> = HashMap::new();
+        properties.insert("name".into(), b.of("name", "marko").into());
+        let merged = g.merge_v(properties).bind("name", "josh");
+
+        let mut bindings = HashMap::new();
+        bindings.insert(String::from("name"), GValue::from("josh"));
+        assert_eq!(bindings, merged.bytecode().bindings());
+    }
+
     //TODO add tests for mergeV, etc
 }
@@ -0,0 +1,1363 @@
+//! An in-process engine running [`Bytecode`] against an in-memory [`TinkerGraph`], so code
+//! building traversals can be tested without a Gremlin Server.
+//!
+//! Only a subset of the steps is supported:
+//!
+//! - sources: `V`, `E`, `addV`, `addE` (with `from`/`to`), `inject`
+//! - filters: `has`, `hasLabel`, `hasId`, `hasNot`, `is`, `limit`, `range`, `dedup`
+//! - navigation: `out`, `in`, `both`, `outE`, `inE`, `bothE`, `outV`, `inV`
+//! - maps: `values`, `valueMap`, `elementMap`, `id`, `label`, `identity`, `unfold`
+//! - mutations: `property`, `drop`
+//! - barriers: `count`, `fold`, `group`, `groupCount`, `order`, modulated by `by`; groups must
+//!   be keyed by strings, tokens or elements
+//! - branches: `repeat` with `until`, `emit` and `times`
+//!
+//! Predicates are limited to `eq`, `neq`, `gt`, `gte`, `lt`, `lte`, `within`, `without` and the
+//! `TextP` string predicates. Source instructions such as `withStrategies` are ignored. Any
+//! other step fails with [`GremlinError::Generic`].
+
+use crate::conversion::{FromGValue, ToGValue};
+use crate::prelude::{GremlinError, GremlinResult};
+use crate::process::traversal::bytecode::Instruction;
+use crate::process::traversal::{Bytecode, GraphTraversal, Order, Results, Scope, Terminator};
+use crate::structure::{
+    Cardinality, Direction, Edge, GKey, GValue, List, TextP, TinkerGraph, TraversalExplanation,
+    TraversalMetrics, Vertex, VertexProperty, GID, P, T,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Executes traversals in process against a shared [`TinkerGraph`].
+///
+/// Clones share the same graph, so mutations made by one traversal are visible to the next.
+#[derive(Clone)]
+pub struct LocalTerminator {
+    graph: Arc<Mutex<LocalGraph>>,
+}
+
+impl LocalTerminator {
+    pub fn new(graph: TinkerGraph) -> LocalTerminator {
+        let next_id = graph
+            .vertices
+            .iter()
+            .map(|v| &v.id)
+            .chain(graph.edges.iter().map(|e| &e.id))
+            .filter_map(|id| match id {
+                GID::Int32(id) => Some(*id as i64),
+                GID::Int64(id) => Some(*id),
+                GID::String(_) => None,
+            })
+            .max()
+            .map_or(0, |id| id + 1);
+
+        LocalTerminator {
+            graph: Arc::new(Mutex::new(LocalGraph { graph, next_id })),
+        }
+    }
+
+    /// A snapshot of the graph in its current state.
+    pub fn graph(&self) -> TinkerGraph {
+        self.lock().graph.clone()
+    }
+
+    /// Runs `bytecode` and returns the raw results.
+    pub fn execute(&self, bytecode: &Bytecode) -> GremlinResult<Vec<GValue>> {
+        self.lock().run(bytecode, vec![GValue::Null], true)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LocalGraph> {
+        // A panic while holding the lock cannot leave the graph half-updated in a way later
+        // traversals would trip on, so a poisoned lock is still usable.
+        self.graph.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn results(&self, bytecode: &Bytecode) -> GremlinResult<Results> {
+        Ok(Results::new(self.execute(bytecode)?))
+    }
+}
+
+impl Default for LocalTerminator {
+    fn default() -> Self {
+        LocalTerminator::new(TinkerGraph::new(vec![], vec![]))
+    }
+}
+
+impl<T: FromGValue> Terminator<T> for LocalTerminator {
+    type List = GremlinResult<Vec<T>>;
+    type Next = GremlinResult<Option<T>>;
+    type One = GremlinResult<T>;
+    type NextN = GremlinResult<Vec<T>>;
+    type HasNext = GremlinResult<bool>;
+    type Iter = GremlinResult<std::vec::IntoIter<T>>;
+    type Profile = GremlinResult<TraversalMetrics>;
+    type Explain = GremlinResult<TraversalExplanation>;
+    type Traversers = GremlinResult<std::vec::IntoIter<(T, i64)>>;
+
+    fn to_list<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::List
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode())?.to_list()
+    }
+
+    fn next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Next
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode())?.next()
+    }
+
+    fn one<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::One
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode())?.one()
+    }
+
+    fn try_next_n<S, E>(&self, traversal: &GraphTraversal<S, T, E>, n: usize) -> Self::NextN
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode())?.try_next_n(n)
+    }
+
+    fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
+    where
+        E: Terminator<T>,
+    {
+        Ok(self.results(traversal.bytecode())?.has_next())
+    }
+
+    fn iter<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Iter
+    where
+        E: Terminator<T>,
+    {
+        Ok(self.results(traversal.bytecode())?.to_list()?.into_iter())
+    }
+
+    fn profile<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Profile
+    where
+        E: Terminator<T>,
+    {
+        Err(unsupported("profile"))
+    }
+
+    fn explain<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Explain
+    where
+        E: Terminator<T>,
+    {
+        Err(unsupported("explain"))
+    }
+
+    fn iter_traversers<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Traversers
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode())?.iter_traversers()
+    }
+}
+
+fn unsupported(step: &str) -> GremlinError {
+    GremlinError::Generic(format!("{}() is not supported by the local engine", step))
+}
+
+/// An integer argument, which the parser reads as an `Int32` unless suffixed with `L`.
+fn integer(value: &GValue) -> Option<i64> {
+    match value {
+        GValue::Int64(n) => Some(*n),
+        GValue::Int32(n) => Some(*n as i64),
+        _ => None,
+    }
+}
+
+fn invalid(step: &str, args: &[GValue]) -> GremlinError {
+    GremlinError::Generic(format!("Invalid arguments for {}(): {:?}", step, args))
+}
+
+/// A step with the modulators that follow it (`by`, `from`, `to`, `until`, `emit`, `times`)
+/// and, for `repeat`, the `until`/`emit` placed before it.
+struct Op<'a> {
+    step: &'a Instruction,
+    before: Vec<&'a Instruction>,
+    after: Vec<&'a Instruction>,
+}
+
+impl<'a> Op<'a> {
+    fn modulators(&self, name: &str) -> impl Iterator<Item = &'a Instruction> + '_ {
+        let name = String::from(name);
+        self.after
+            .iter()
+            .copied()
+            .filter(move |i| i.operator == name)
+    }
+
+    fn modulator(&self, name: &str) -> Option<&'a Instruction> {
+        self.modulators(name).next()
+    }
+}
+
+fn plan(steps: &[Instruction]) -> GremlinResult<Vec<Op<'_>>> {
+    let mut ops: Vec<Op> = vec![];
+    let mut pending = vec![];
+    let mut in_repeat = false;
+
+    for step in steps {
+        match step.operator.as_str() {
+            "by" | "from" | "to" => match ops.last_mut() {
+                Some(op) => op.after.push(step),
+                None => return Err(invalid(&step.operator, &step.args)),
+            },
+            "until" | "emit" | "times" if in_repeat => match ops.last_mut() {
+                Some(op) => op.after.push(step),
+                None => return Err(invalid(&step.operator, &step.args)),
+            },
+            "until" | "emit" => pending.push(step),
+            _ => {
+                if step.operator != "repeat" && !pending.is_empty() {
+                    return Err(GremlinError::Generic(String::from(
+                        "until() and emit() must be followed by repeat()",
+                    )));
+                }
+                in_repeat = step.operator == "repeat";
+                ops.push(Op {
+                    step,
+                    before: std::mem::take(&mut pending),
+                    after: vec![],
+                });
+                continue;
+            }
+        }
+        if !matches!(step.operator.as_str(), "until" | "emit" | "times") {
+            in_repeat = false;
+        }
+    }
+
+    if !pending.is_empty() {
+        return Err(GremlinError::Generic(String::from(
+            "until() and emit() must be followed by repeat()",
+        )));
+    }
+    Ok(ops)
+}
+
+struct LocalGraph {
+    graph: TinkerGraph,
+    next_id: i64,
+}
+
+impl LocalGraph {
+    /// Runs the steps of `bytecode` over `traversers`. The first step of a root traversal is a
+    /// start step, fed a single placeholder traverser.
+    fn run(
+        &mut self,
+        bytecode: &Bytecode,
+        mut traversers: Vec<GValue>,
+        root: bool,
+    ) -> GremlinResult<Vec<GValue>> {
+        for (i, op) in plan(bytecode.steps())?.iter().enumerate() {
+            traversers = self.step(op, traversers, root && i == 0)?;
+        }
+        Ok(traversers)
+    }
+
+    fn id(&mut self) -> GID {
+        let id = self.next_id;
+        self.next_id += 1;
+        GID::Int64(id)
+    }
+
+    fn step(&mut self, op: &Op, input: Vec<GValue>, start: bool) -> GremlinResult<Vec<GValue>> {
+        let name = op.step.operator.as_str();
+        let args = &op.step.args;
+
+        Ok(match name {
+            "V" => {
+                let mut out = vec![];
+                for _ in &input {
+                    out.extend(
+                        self.graph
+                            .vertices
+                            .iter()
+                            .filter(|v| args.is_empty() || contains_id(args, &v.id))
+                            .cloned()
+                            .map(GValue::Vertex),
+                    );
+                }
+                out
+            }
+            "E" => {
+                let mut out = vec![];
+                for _ in &input {
+                    out.extend(
+                        self.graph
+                            .edges
+                            .iter()
+                            .filter(|e| args.is_empty() || contains_id(args, &e.id))
+                            .cloned()
+                            .map(GValue::Edge),
+                    );
+                }
+                out
+            }
+            "inject" => {
+                let mut out = if start { vec![] } else { input };
+                out.extend(args.iter().cloned());
+                out
+            }
+            "addV" => {
+                let label = match args.as_slice() {
+                    [] => String::from("vertex"),
+                    [GValue::String(label)] => label.clone(),
+                    _ => return Err(invalid(name, args)),
+                };
+                let mut out = vec![];
+                for _ in &input {
+                    let vertex = Vertex::new(self.id(), label.clone(), HashMap::new());
+                    self.graph.vertices.push(vertex.clone());
+                    out.push(GValue::Vertex(vertex));
+                }
+                out
+            }
+            "addE" => {
+                let label = match args.as_slice() {
+                    [GValue::String(label)] => label.clone(),
+                    _ => return Err(invalid(name, args)),
+                };
+                let mut out = vec![];
+                for traverser in input {
+                    let from = self.endpoint(op.modulator("from"), &traverser)?;
+                    let to = self.endpoint(op.modulator("to"), &traverser)?;
+                    let edge = Edge::new(
+                        self.id(),
+                        label.clone(),
+                        to.id.clone(),
+                        to.label.clone(),
+                        from.id.clone(),
+                        from.label.clone(),
+                        HashMap::new(),
+                    );
+                    self.graph.edges.push(edge.clone());
+                    out.push(GValue::Edge(edge));
+                }
+                out
+            }
+            "property" => {
+                let (cardinality, key, value) = match args.as_slice() {
+                    [GValue::String(key), value] => (Cardinality::Single, key, value),
+                    [GValue::Cardinality(c), GValue::String(key), value] => (c.clone(), key, value),
+                    _ => return Err(invalid(name, args)),
+                };
+                let mut out = vec![];
+                for traverser in input {
+                    out.push(self.set_property(traverser, &cardinality, key, value)?);
+                }
+                out
+            }
+            "drop" => {
+                for traverser in input {
+                    match traverser {
+                        GValue::Vertex(v) => {
+                            self.graph.vertices.retain(|o| o.id != v.id);
+                            self.graph
+                                .edges
+                                .retain(|e| e.in_v.id != v.id && e.out_v.id != v.id);
+                        }
+                        GValue::Edge(e) => self.graph.edges.retain(|o| o.id != e.id),
+                        other => {
+                            return Err(GremlinError::Generic(format!("Cannot drop {:?}", other)))
+                        }
+                    }
+                }
+                vec![]
+            }
+            "has" => {
+                let (label, key, predicate) = match args.as_slice() {
+                    [key] => (None, key, None),
+                    [key, predicate] => (None, key, Some(predicate)),
+                    [GValue::String(label), key, predicate] => (Some(label), key, Some(predicate)),
+                    _ => return Err(invalid(name, args)),
+                };
+                let mut out = vec![];
+                for traverser in input {
+                    if label.map_or(true, |l| element_label(&traverser) == Some(l.as_str())) {
+                        let values = self.key_values(&traverser, key)?;
+                        let matched = match predicate {
+                            None => !values.is_empty(),
+                            Some(predicate) => {
+                                let mut matched = false;
+                                for value in &values {
+                                    matched |= test(predicate, value)?;
+                                }
+                                matched
+                            }
+                        };
+                        if matched {
+                            out.push(traverser);
+                        }
+                    }
+                }
+                out
+            }
+            "hasLabel" => {
+                let mut out = vec![];
+                for traverser in input {
+                    let label = element_label(&traverser).map(GValue::from);
+                    let mut matched = false;
+                    if let Some(label) = &label {
+                        for arg in args {
+                            matched |= test(arg, label)?;
+                        }
+                    }
+                    if matched {
+                        out.push(traverser);
+                    }
+                }
+                out
+            }
+            "hasId" => input
+                .into_iter()
+                .filter(|t| element_id(t).map_or(false, |id| contains_id(args, id)))
+                .collect(),
+            "hasNot" => {
+                let key = args.first().ok_or_else(|| invalid(name, args))?;
+                let mut out = vec![];
+                for traverser in input {
+                    if self.key_values(&traverser, key)?.is_empty() {
+                        out.push(traverser);
+                    }
+                }
+                out
+            }
+            "is" => {
+                let predicate = args.first().ok_or_else(|| invalid(name, args))?;
+                let mut out = vec![];
+                for traverser in input {
+                    if test(predicate, &traverser)? {
+                        out.push(traverser);
+                    }
+                }
+                out
+            }
+            "out" | "in" | "both" | "outE" | "inE" | "bothE" => {
+                let labels = strings(name, args)?;
+                let edges_only = name.ends_with('E');
+                let directions: &[Direction] = match name {
+                    "out" | "outE" => &[Direction::Out],
+                    "in" | "inE" => &[Direction::In],
+                    _ => &[Direction::Out, Direction::In],
+                };
+                let mut out = vec![];
+                for traverser in input {
+                    let vertex = match traverser {
+                        GValue::Vertex(v) => v,
+                        other => {
+                            return Err(GremlinError::Generic(format!(
+                                "{}() expects vertices, got {:?}",
+                                name, other
+                            )))
+                        }
+                    };
+                    for direction in directions {
+                        for edge in self.graph.vertex_edges(&vertex.id, direction.clone()) {
+                            if !labels.is_empty() && !labels.contains(&edge.label) {
+                                continue;
+                            }
+                            if edges_only {
+                                out.push(GValue::Edge(edge.clone()));
+                                continue;
+                            }
+                            let other = match direction {
+                                Direction::Out | Direction::From => &edge.in_v.id,
+                                Direction::In | Direction::To => &edge.out_v.id,
+                            };
+                            if let Some(v) = self.graph.vertex(other) {
+                                out.push(GValue::Vertex(v.clone()));
+                            }
+                        }
+                    }
+                }
+                out
+            }
+            "outV" | "inV" => {
+                let mut out = vec![];
+                for traverser in input {
+                    let edge = match traverser {
+                        GValue::Edge(e) => e,
+                        other => {
+                            return Err(GremlinError::Generic(format!(
+                                "{}() expects edges, got {:?}",
+                                name, other
+                            )))
+                        }
+                    };
+                    let id = if name == "outV" {
+                        &edge.out_v.id
+                    } else {
+                        &edge.in_v.id
+                    };
+                    if let Some(v) = self.graph.vertex(id) {
+                        out.push(GValue::Vertex(v.clone()));
+                    }
+                }
+                out
+            }
+            "values" => {
+                let keys = strings(name, args)?;
+                let mut out = vec![];
+                for traverser in &input {
+                    out.extend(properties(traverser, &keys).into_iter().map(|(_, v)| v));
+                }
+                out
+            }
+            "valueMap" => {
+                let tokens = matches!(args.first(), Some(GValue::Bool(true)));
+                let keys = strings(name, &args[usize::from(tokens)..])?;
+                let mut out = vec![];
+                for traverser in &input {
+                    let mut map: HashMap<GKey, GValue> = HashMap::new();
+                    let vertex = matches!(traverser, GValue::Vertex(_));
+                    for (key, value) in properties(traverser, &keys) {
+                        if vertex {
+                            let entry = map
+                                .entry(GKey::String(key))
+                                .or_insert_with(|| GValue::List(List::new(vec![])));
+                            if let GValue::List(list) = entry {
+                                list.0.push(value);
+                            }
+                        } else {
+                            map.insert(GKey::String(key), value);
+                        }
+                    }
+                    if tokens {
+                        insert_tokens(&mut map, traverser);
+                    }
+                    out.push(map.into());
+                }
+                out
+            }
+            "elementMap" => {
+                let keys = strings(name, args)?;
+                let mut out = vec![];
+                for traverser in &input {
+                    let mut map: HashMap<GKey, GValue> = HashMap::new();
+                    insert_tokens(&mut map, traverser);
+                    for (key, value) in properties(traverser, &keys) {
+                        map.entry(GKey::String(key)).or_insert(value);
+                    }
+                    if let GValue::Edge(edge) = traverser {
+                        for (direction, vertex) in
+                            vec![(Direction::In, &edge.in_v), (Direction::Out, &edge.out_v)]
+                        {
+                            let mut endpoint: HashMap<GKey, GValue> = HashMap::new();
+                            endpoint.insert(GKey::T(T::Id), vertex.id.to_gvalue());
+                            endpoint.insert(GKey::T(T::Label), vertex.label.clone().into());
+                            map.insert(GKey::Direction(direction), endpoint.into());
+                        }
+                    }
+                    out.push(map.into());
+                }
+                out
+            }
+            "id" => input
+                .iter()
+                .filter_map(|t| element_id(t).map(ToGValue::to_gvalue))
+                .collect(),
+            "label" => input
+                .iter()
+                .filter_map(|t| element_label(t).map(GValue::from))
+                .collect(),
+            "identity" => input,
+            "unfold" => input
+                .into_iter()
+                .flat_map(|t| match t {
+                    GValue::List(list) => list.into_iter().collect(),
+                    GValue::Set(set) => set.into_iter().collect(),
+                    GValue::Map(map) => map
+                        .into_iter()
+                        .map(|(k, v)| {
+                            let mut entry = HashMap::new();
+                            entry.insert(k, v);
+                            entry.into()
+                        })
+                        .collect(),
+                    other => vec![other],
+                })
+                .collect(),
+            "count" => {
+                global(name, args)?;
+                vec![GValue::Int64(input.len() as i64)]
+            }
+            "fold" => vec![GValue::List(List::new(input))],
+            "limit" => {
+                let limit = match args.as_slice() {
+                    [n] | [GValue::Scope(Scope::Global), n] => n,
+                    [GValue::Scope(Scope::Local), _] => return Err(unsupported("limit(local)")),
+                    _ => return Err(invalid(name, args)),
+                };
+                let limit = integer(limit).ok_or_else(|| invalid(name, args))?;
+                // As with the high end of range(), a negative limit keeps everything.
+                if limit < 0 {
+                    input
+                } else {
+                    input.into_iter().take(limit as usize).collect()
+                }
+            }
+            "range" => {
+                let (low, high) = match args.as_slice() {
+                    [low, high] => match (integer(low), integer(high)) {
+                        (Some(low), Some(high)) => (low, high),
+                        _ => return Err(invalid(name, args)),
+                    },
+                    _ => return Err(invalid(name, args)),
+                };
+                let skipped = input.into_iter().skip(low.max(0) as usize);
+                if high < 0 {
+                    skipped.collect()
+                } else {
+                    skipped.take((high - low).max(0) as usize).collect()
+                }
+            }
+            "dedup" => {
+                if !args.is_empty() {
+                    return Err(invalid(name, args));
+                }
+                let by = op.modulator("by").and_then(|b| b.args.first());
+                let mut seen: Vec<GValue> = vec![];
+                let mut out = vec![];
+                for traverser in input {
+                    let key = self.by_value(by, &traverser)?;
+                    if !seen.iter().any(|s| equals(s, &key)) {
+                        seen.push(key);
+                        out.push(traverser);
+                    }
+                }
+                out
+            }
+            "order" => {
+                global(name, args)?;
+                let mut keyed = vec![];
+                for traverser in input {
+                    let mut keys = vec![];
+                    for by in op.modulators("by") {
+                        let (spec, order) = match by.args.as_slice() {
+                            [] => (None, Order::Asc),
+                            [GValue::Order(order)] => (None, order.clone()),
+                            [spec] => (Some(spec), Order::Asc),
+                            [spec, GValue::Order(order)] => (Some(spec), order.clone()),
+                            _ => return Err(invalid("by", &by.args)),
+                        };
+                        keys.push((self.by_value(spec, &traverser)?, order));
+                    }
+                    if keys.is_empty() {
+                        keys.push((traverser.clone(), Order::Asc));
+                    }
+                    keyed.push((keys, traverser));
+                }
+                if keyed
+                    .iter()
+                    .any(|(keys, _)| keys.iter().any(|(_, o)| *o == Order::Shuffle))
+                {
+                    return Err(unsupported("order().by(shuffle)"));
+                }
+                keyed.sort_by(|(a, _), (b, _)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .map(|((a, order), (b, _))| match order {
+                            Order::Desc => compare(b, a),
+                            _ => compare(a, b),
+                        })
+                        .find(|o| *o != Ordering::Equal)
+                        .unwrap_or(Ordering::Equal)
+                });
+                keyed.into_iter().map(|(_, t)| t).collect()
+            }
+            "group" | "groupCount" => {
+                if !args.is_empty() {
+                    return Err(unsupported(&format!("{}(sideEffectKey)", name)));
+                }
+                let mut bys = op.modulators("by").map(|b| b.args.first());
+                let key_by = bys.next().flatten();
+                let value_by = bys.next().flatten();
+
+                let mut groups: Vec<(GValue, Vec<GValue>)> = vec![];
+                for traverser in input {
+                    let key = self.by_value(key_by, &traverser)?;
+                    match groups.iter_mut().find(|(k, _)| equals(k, &key)) {
+                        Some((_, members)) => members.push(traverser),
+                        None => groups.push((key, vec![traverser])),
+                    }
+                }
+
+                let mut map: HashMap<GKey, GValue> = HashMap::new();
+                for (key, members) in groups {
+                    let value = if name == "groupCount" {
+                        GValue::Int64(members.len() as i64)
+                    } else {
+                        self.group_value(value_by, members)?
+                    };
+                    map.insert(gkey(key)?, value);
+                }
+                vec![map.into()]
+            }
+            "repeat" => self.repeat(op, input)?,
+            other => return Err(unsupported(other)),
+        })
+    }
+
+    /// The vertex an edge starts or ends at: the `from`/`to` modulator when present, the
+    /// current traverser otherwise.
+    fn endpoint(
+        &mut self,
+        modulator: Option<&Instruction>,
+        traverser: &GValue,
+    ) -> GremlinResult<Vertex> {
+        let value = match modulator.map(|m| m.args.as_slice()) {
+            None => traverser.clone(),
+            Some([GValue::Bytecode(bytecode)]) => self
+                .run(bytecode, vec![traverser.clone()], false)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    GremlinError::Generic(String::from("addE() endpoint traversal is empty"))
+                })?,
+            Some([GValue::String(_)]) => return Err(unsupported("addE().from/to(stepLabel)")),
+            Some([value]) => value.clone(),
+            Some(args) => return Err(invalid("addE", args)),
+        };
+
+        let id = match &value {
+            GValue::Vertex(v) => v.id.clone(),
+            GValue::Int32(id) => GID::Int32(*id),
+            GValue::Int64(id) => GID::Int64(*id),
+            other => {
+                return Err(GremlinError::Generic(format!(
+                    "{:?} is not a vertex",
+                    other
+                )))
+            }
+        };
+        self.graph
+            .vertices
+            .iter()
+            .find(|v| gid_equals(&v.id, &id))
+            .cloned()
+            .ok_or_else(|| GremlinError::Generic(format!("Vertex {:?} not found", id)))
+    }
+
+    fn set_property(
+        &mut self,
+        traverser: GValue,
+        cardinality: &Cardinality,
+        key: &str,
+        value: &GValue,
+    ) -> GremlinResult<GValue> {
+        match traverser {
+            GValue::Vertex(vertex) => {
+                let mut property = VertexProperty::new(self.id(), key, value.clone());
+                property.vertex = Some(vertex.id.clone());
+
+                let stored = self
+                    .graph
+                    .vertices
+                    .iter_mut()
+                    .find(|v| v.id == vertex.id)
+                    .ok_or_else(|| {
+                        GremlinError::Generic(format!("Vertex {:?} not found", vertex.id))
+                    })?;
+                let values = stored.properties.entry(String::from(key)).or_default();
+                match cardinality {
+                    Cardinality::Single => *values = vec![property],
+                    Cardinality::List => values.push(property),
+                    Cardinality::Set => {
+                        if !values.iter().any(|p| equals(&p.value, value)) {
+                            values.push(property);
+                        }
+                    }
+                }
+                Ok(GValue::Vertex(stored.clone()))
+            }
+            GValue::Edge(edge) => {
+                let stored = self
+                    .graph
+                    .edges
+                    .iter_mut()
+                    .find(|e| e.id == edge.id)
+                    .ok_or_else(|| {
+                        GremlinError::Generic(format!("Edge {:?} not found", edge.id))
+                    })?;
+                stored
+                    .properties
+                    .insert(String::from(key), Box::new(value.clone()));
+                Ok(GValue::Edge(stored.clone()))
+            }
+            other => Err(GremlinError::Generic(format!(
+                "Cannot set a property on {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// The values `has()` and `hasNot()` look at for `key`, a property name or a `T` token.
+    fn key_values(&self, traverser: &GValue, key: &GValue) -> GremlinResult<Vec<GValue>> {
+        Ok(match key {
+            GValue::String(key) => properties(traverser, &[key.clone()])
+                .into_iter()
+                .map(|(_, v)| v)
+                .collect(),
+            GValue::T(T::Id) => element_id(traverser)
+                .map(|id| id.to_gvalue())
+                .into_iter()
+                .collect(),
+            GValue::T(T::Label) => element_label(traverser)
+                .map(GValue::from)
+                .into_iter()
+                .collect(),
+            other => return Err(invalid("has", &[other.clone()])),
+        })
+    }
+
+    /// Applies a `by()` modulator argument to a traverser: a property name, a `T` token or an
+    /// anonymous traversal. Without an argument the traverser itself is used.
+    fn by_value(&mut self, spec: Option<&GValue>, traverser: &GValue) -> GremlinResult<GValue> {
+        Ok(match spec {
+            None => traverser.clone(),
+            Some(GValue::Bytecode(bytecode)) => self
+                .run(bytecode, vec![traverser.clone()], false)?
+                .into_iter()
+                .next()
+                .unwrap_or(GValue::Null),
+            Some(key) => self
+                .key_values(traverser, key)?
+                .into_iter()
+                .next()
+                .unwrap_or(GValue::Null),
+        })
+    }
+
+    /// The value of a `group()` entry: the members themselves, a property of each member, or
+    /// the result of a traversal over all members, folded unless it ends with a reducing step.
+    fn group_value(
+        &mut self,
+        spec: Option<&GValue>,
+        members: Vec<GValue>,
+    ) -> GremlinResult<GValue> {
+        match spec {
+            None => Ok(GValue::List(List::new(members))),
+            Some(GValue::Bytecode(bytecode)) => {
+                let mut results = self.run(bytecode, members, false)?;
+                let reducing = bytecode.steps().last().map_or(false, |s| {
+                    matches!(
+                        s.operator.as_str(),
+                        "count" | "fold" | "groupCount" | "group"
+                    )
+                });
+                if reducing && results.len() == 1 {
+                    Ok(results.remove(0))
+                } else {
+                    Ok(GValue::List(List::new(results)))
+                }
+            }
+            Some(key) => {
+                let mut values = vec![];
+                for member in &members {
+                    values.extend(self.key_values(member, key)?);
+                }
+                Ok(GValue::List(List::new(values)))
+            }
+        }
+    }
+
+    fn repeat(&mut self, op: &Op, input: Vec<GValue>) -> GremlinResult<Vec<GValue>> {
+        let body = match op.step.args.as_slice() {
+            [GValue::Bytecode(body)] => body,
+            args => return Err(invalid("repeat", args)),
+        };
+        let find = |mods: &[&Instruction], name: &str| -> Option<Vec<GValue>> {
+            mods.iter()
+                .find(|m| m.operator == name)
+                .map(|m| m.args.clone())
+        };
+        let until_before = find(&op.before, "until");
+        let emit_before = find(&op.before, "emit");
+        let until_after = find(&op.after, "until");
+        let emit_after = find(&op.after, "emit");
+        let times = match find(&op.after, "times").as_deref() {
+            None => None,
+            Some([GValue::Int32(n)]) => Some(*n as usize),
+            Some([GValue::Int64(n)]) => Some(*n as usize),
+            Some(args) => return Err(invalid("times", args)),
+        };
+
+        let mut current = input;
+        let mut out = vec![];
+        let mut loops = 0;
+
+        loop {
+            if let Some(condition) = &until_before {
+                let (done, rest) = self.split(condition, current)?;
+                out.extend(done);
+                current = rest;
+            }
+            if let Some(condition) = &emit_before {
+                let (emitted, _) = self.split(condition, current.clone())?;
+                out.extend(emitted);
+            }
+            if current.is_empty() {
+                break;
+            }
+
+            current = self.run(body, current, false)?;
+            loops += 1;
+
+            if times.map_or(false, |times| loops >= times) {
+                out.extend(current);
+                break;
+            }
+            if let Some(condition) = &until_after {
+                let (done, rest) = self.split(condition, current)?;
+                out.extend(done);
+                current = rest;
+            }
+            if let Some(condition) = &emit_after {
+                let (emitted, _) = self.split(condition, current.clone())?;
+                out.extend(emitted);
+            }
+            if current.is_empty() {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Splits traversers on an `until`/`emit` condition: an anonymous traversal that must yield
+    /// a result, or no condition at all, which always holds.
+    fn split(
+        &mut self,
+        condition: &[GValue],
+        traversers: Vec<GValue>,
+    ) -> GremlinResult<(Vec<GValue>, Vec<GValue>)> {
+        let mut matched = vec![];
+        let mut rest = vec![];
+        for traverser in traversers {
+            let holds = match condition {
+                [] => true,
+                [GValue::Bytecode(bytecode)] => !self
+                    .run(bytecode, vec![traverser.clone()], false)?
+                    .is_empty(),
+                args => return Err(invalid("until", args)),
+            };
+            if holds {
+                matched.push(traverser);
+            } else {
+                rest.push(traverser);
+            }
+        }
+        Ok((matched, rest))
+    }
+}
+
+fn global(step: &str, args: &[GValue]) -> GremlinResult<()> {
+    match args {
+        [] | [GValue::Scope(Scope::Global)] => Ok(()),
+        [GValue::Scope(Scope::Local)] => Err(unsupported(&format!("{}(local)", step))),
+        _ => Err(invalid(step, args)),
+    }
+}
+
+fn strings(step: &str, args: &[GValue]) -> GremlinResult<Vec<String>> {
+    args.iter()
+        .map(|a| match a {
+            GValue::String(s) => Ok(s.clone()),
+            _ => Err(invalid(step, args)),
+        })
+        .collect()
+}
+
+fn element_id(value: &GValue) -> Option<&GID> {
+    match value {
+        GValue::Vertex(v) => Some(&v.id),
+        GValue::Edge(e) => Some(&e.id),
+        GValue::VertexProperty(p) => Some(&p.id),
+        _ => None,
+    }
+}
+
+fn element_label(value: &GValue) -> Option<&str> {
+    match value {
+        GValue::Vertex(v) => Some(&v.label),
+        GValue::Edge(e) => Some(&e.label),
+        GValue::VertexProperty(p) => Some(&p.label),
+        _ => None,
+    }
+}
+
+/// Property keys and values of an element, restricted to `keys` unless empty.
+fn properties(value: &GValue, keys: &[String]) -> Vec<(String, GValue)> {
+    let wanted = |key: &String| keys.is_empty() || keys.contains(key);
+    let mut properties = match value {
+        GValue::Vertex(v) => v
+            .properties
+            .iter()
+            .filter(|(k, _)| wanted(k))
+            .flat_map(|(k, values)| values.iter().map(move |p| (k.clone(), (*p.value).clone())))
+            .collect::<Vec<_>>(),
+        GValue::Edge(e) => e
+            .properties
+            .iter()
+            .filter(|(k, _)| wanted(k))
+            .map(|(k, v)| (k.clone(), (**v).clone()))
+            .collect(),
+        GValue::Map(map) => map
+            .iter()
+            .filter_map(|(k, v)| match k {
+                GKey::String(k) if wanted(k) => Some((k.clone(), v.clone())),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    // Properties live in hash maps; follow the requested key order, then sort by key.
+    properties.sort_by_key(|(k, _)| {
+        (
+            keys.iter().position(|w| w == k).unwrap_or(usize::MAX),
+            k.clone(),
+        )
+    });
+    properties
+}
+
+fn insert_tokens(map: &mut HashMap<GKey, GValue>, element: &GValue) {
+    if let Some(id) = element_id(element) {
+        map.insert(GKey::T(T::Id), id.to_gvalue());
+    }
+    if let Some(label) = element_label(element) {
+        map.insert(GKey::T(T::Label), label.into());
+    }
+}
+
+fn gkey(value: GValue) -> GremlinResult<GKey> {
+    match value {
+        GValue::String(s) => Ok(GKey::String(s)),
+        GValue::T(t) => Ok(GKey::T(t)),
+        GValue::Token(t) => Ok(GKey::Token(t)),
+        GValue::Vertex(v) => Ok(GKey::Vertex(v)),
+        GValue::Edge(e) => Ok(GKey::Edge(e)),
+        GValue::Direction(d) => Ok(GKey::Direction(d)),
+        // Map keys cannot hold other values.
+        other => Err(GremlinError::Generic(format!(
+            "Grouping by {:?} is not supported by the local engine",
+            other
+        ))),
+    }
+}
+
+fn gid_equals(a: &GID, b: &GID) -> bool {
+    equals(&a.to_gvalue(), &b.to_gvalue())
+}
+
+fn contains_id(ids: &[GValue], id: &GID) -> bool {
+    let id = id.to_gvalue();
+    ids.iter().any(|candidate| match candidate {
+        GValue::Vertex(v) => equals(&v.id.to_gvalue(), &id),
+        GValue::Edge(e) => equals(&e.id.to_gvalue(), &id),
+        GValue::List(list) => list.iter().any(|c| equals(c, &id)),
+        candidate => equals(candidate, &id),
+    })
+}
+
+fn number(value: &GValue) -> Option<f64> {
+    match value {
+        GValue::Int32(n) => Some(*n as f64),
+        GValue::Int64(n) => Some(*n as f64),
+        GValue::Float(n) => Some(*n as f64),
+        GValue::Double(n) => Some(*n),
+        _ => None,
+    }
+}
+
+/// Equality where numbers compare by value regardless of their width.
+fn equals(a: &GValue, b: &GValue) -> bool {
+    match (number(a), number(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Orders numbers, strings, booleans and dates among themselves; values of different kinds
+/// are ordered by kind, with nulls first.
+fn compare(a: &GValue, b: &GValue) -> Ordering {
+    if let (Some(a), Some(b)) = (number(a), number(b)) {
+        return a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+    }
+    match (a, b) {
+        (GValue::String(a), GValue::String(b)) => a.cmp(b),
+        (GValue::Bool(a), GValue::Bool(b)) => a.cmp(b),
+        (GValue::Date(a), GValue::Date(b)) => a.cmp(b),
+        _ => kind(a).cmp(&kind(b)),
+    }
+}
+
+fn kind(value: &GValue) -> u8 {
+    match value {
+        GValue::Null => 0,
+        GValue::Bool(_) => 1,
+        GValue::Int32(_) | GValue::Int64(_) | GValue::Float(_) | GValue::Double(_) => 2,
+        GValue::Date(_) => 3,
+        GValue::String(_) => 4,
+        _ => 5,
+    }
+}
+
+/// Tests a value against a `P`, a `TextP`, or a plain value compared for equality.
+fn test(predicate: &GValue, value: &GValue) -> GremlinResult<bool> {
+    match predicate {
+        GValue::P(p) => test_p(p, value),
+        GValue::TextP(p) => test_text_p(p, value),
+        other => Ok(equals(other, value)),
+    }
+}
+
+fn test_p(p: &P, value: &GValue) -> GremlinResult<bool> {
+    let operand = p.value();
+    let within = || match operand {
+        GValue::List(list) => list.iter().any(|o| equals(o, value)),
+        other => equals(other, value),
+    };
+    let ordered = |check: fn(Ordering) -> bool| {
+        kind(operand) == kind(value) && check(compare(value, operand))
+    };
+
+    Ok(match p.operator().as_str() {
+        "eq" => equals(operand, value),
+        "neq" => !equals(operand, value),
+        "gt" => ordered(|o| o == Ordering::Greater),
+        "gte" => ordered(|o| o != Ordering::Less),
+        "lt" => ordered(|o| o == Ordering::Less),
+        "lte" => ordered(|o| o != Ordering::Greater),
+        "within" => within(),
+        "without" => !within(),
+        other => return Err(unsupported(&format!("P.{}", other))),
+    })
+}
+
+fn test_text_p(p: &TextP, value: &GValue) -> GremlinResult<bool> {
+    let (value, operand) = match (value, p.value()) {
+        (GValue::String(value), GValue::String(operand)) => (value, operand),
+        _ => return Ok(false),
+    };
+
+    Ok(match p.operator().as_str() {
+        "containing" => value.contains(operand.as_str()),
+        "notContaining" => !value.contains(operand.as_str()),
+        "startingWith" => value.starts_with(operand.as_str()),
+        "notStartingWith" => !value.starts_with(operand.as_str()),
+        "endingWith" => value.ends_with(operand.as_str()),
+        "notEndingWith" => !value.ends_with(operand.as_str()),
+        other => return Err(unsupported(&format!("TextP.{}", other))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LocalTerminator;
+    use crate::process::traversal::{GraphTraversalSource, Order, __};
+    use crate::structure::{GValue, List, Map, TinkerGraph, Vertex, P, T};
+
+    fn modern() -> GraphTraversalSource<LocalTerminator> {
+        let g = GraphTraversalSource::new(LocalTerminator::new(TinkerGraph::new(vec![], vec![])));
+
+        for (name, age) in &[("marko", 29), ("vadas", 27), ("josh", 32), ("peter", 35)] {
+            g.add_v("person")
+                .property("name", *name)
+                .property("age", *age)
+                .to_list()
+                .unwrap();
+        }
+        for (name, lang) in &[("lop", "java"), ("ripple", "java")] {
+            g.add_v("software")
+                .property("name", *name)
+                .property("lang", *lang)
+                .to_list()
+                .unwrap();
+        }
+        for (from, label, to) in &[
+            ("marko", "knows", "vadas"),
+            ("marko", "knows", "josh"),
+            ("marko", "created", "lop"),
+            ("josh", "created", "ripple"),
+            ("josh", "created", "lop"),
+            ("peter", "created", "lop"),
+        ] {
+            g.v(())
+                .has(("name", *from))
+                .add_e(*label)
+                .to(__.v(()).has(("name", *to)))
+                .to_list()
+                .unwrap();
+        }
+        g
+    }
+
+    fn names(values: Vec<GValue>) -> Vec<String> {
+        values
+            .into_iter()
+            .map(|v| v.take::<String>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn local_navigation() {
+        let g = modern();
+
+        assert_eq!(6, g.v(()).count().next().unwrap().unwrap());
+        assert_eq!(6, g.e(()).count().next().unwrap().unwrap());
+
+        let known = g
+            .v(())
+            .has(("person", "name", "marko"))
+            .out("knows")
+            .has(("age", P::gt(30)))
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["josh"], names(known));
+
+        let creators = g
+            .v(())
+            .has(("name", "lop"))
+            .in_("created")
+            .order(())
+            .by(("age", Order::Desc))
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["peter", "josh", "marko"], names(creators));
+
+        let software = g
+            .v(())
+            .has_label("person")
+            .out(())
+            .has_label("software")
+            .dedup(())
+            .count()
+            .next()
+            .unwrap();
+        assert_eq!(Some(2), software);
+    }
+
+    #[test]
+    fn local_maps() {
+        let g = modern();
+
+        let map = g
+            .v(())
+            .has(("name", "marko"))
+            .element_map(())
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(&GValue::from("person")), map.get(T::Label));
+        assert_eq!(Some(&GValue::from(29)), map.get("age"));
+
+        let groups = g
+            .v(())
+            .group()
+            .by(T::Label)
+            .by(__.count())
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(&GValue::Int64(4)), groups.get("person"));
+        assert_eq!(Some(&GValue::Int64(2)), groups.get("software"));
+
+        let counts: Map = g
+            .v(())
+            .out_e(())
+            .label()
+            .group_count()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(&GValue::Int64(4)), counts.get("created"));
+
+        let folded: List = g
+            .v(())
+            .has_label("person")
+            .values("age")
+            .order(())
+            .fold()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            vec![27, 29, 32, 35],
+            folded
+                .into_iter()
+                .map(|v| v.take::<i32>().unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn local_repeat_and_mutations() {
+        let g = modern();
+
+        let reached = g
+            .v(())
+            .has(("name", "marko"))
+            .repeat(__.out(()))
+            .until(__.has_label("software"))
+            .values("name")
+            .dedup(())
+            .order(())
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["lop", "ripple"], names(reached));
+
+        g.v(()).has(("name", "josh")).drop().to_list().unwrap();
+
+        let vertices: Vec<Vertex> = g.v(()).to_list().unwrap();
+        assert_eq!(5, vertices.len());
+        assert_eq!(3, g.e(()).count().next().unwrap().unwrap());
+        assert!(!g.v(()).has(("name", "josh")).has_next().unwrap());
+    }
+
+    #[test]
+    fn local_ranges() {
+        let g = modern();
+        let count = |script: &str| g.parse(script).unwrap().to_list().unwrap().len();
+
+        assert_eq!(2, count("g.V().range(1, 3)"));
+        assert_eq!(5, count("g.V().range(1L, -1L)"));
+        assert_eq!(6, count("g.V().limit(-1)"));
+        assert_eq!(0, count("g.V().limit(0)"));
+
+        assert_eq!(3, g.v(()).try_next_n(3).unwrap().len());
+        assert!(g.v(()).one().is_err());
+        assert!(g.v(()).has(("name", "marko")).one().is_ok());
+    }
+
+    #[test]
+    fn local_unsupported() {
+        let g = modern();
+
+        assert!(g.v(()).path().to_list().is_err());
+        assert!(g.v(()).profile().is_err());
+        assert!(g
+            .parse("g.V().fold().limit(local, 1)")
+            .unwrap()
+            .to_list()
+            .is_err());
+        assert_eq!(
+            2,
+            g.parse("g.V().limit(global, 2)")
+                .unwrap()
+                .to_list()
+                .unwrap()
+                .len()
+        );
+        assert!(g.v(()).group().by("age").next().is_err());
+    }
+}
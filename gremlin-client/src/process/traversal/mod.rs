@@ -1,25 +1,36 @@
 use crate::conversion::FromGValue;
-use crate::prelude::{GResultSet, GraphSON, GremlinResult};
-use crate::structure::Traverser;
+use crate::prelude::{GResultSet, GraphSON, GremlinError, GremlinResult};
+use crate::structure::{GValue, ResponseMetadata};
 use std::marker::PhantomData;
 
 mod anonymous_traversal_source;
+mod bindings;
 mod builder;
 pub(crate) mod bytecode;
+pub mod computer;
 mod graph_traversal;
 mod graph_traversal_source;
+mod local;
 mod order;
+mod parser;
+mod recording;
 pub(crate) mod remote;
 mod scope;
 pub mod step;
 pub mod strategies;
+mod translator;
+pub use bindings::Bindings;
 pub use builder::TraversalBuilder;
-pub use bytecode::{Bytecode, WRITE_OPERATORS};
+pub use bytecode::{Binding, Bytecode, WRITE_OPERATORS};
 pub use graph_traversal::GraphTraversal;
 pub use graph_traversal_source::GraphTraversalSource;
+pub use local::LocalTerminator;
 pub use order::Order;
+pub use parser::{parse, GremlinParser};
+pub use recording::RecordingTerminator;
 pub use remote::{traversal, AsyncTerminator, Terminator};
 pub use scope::Scope;
+pub use translator::{Dialect, Translator};
 
 pub use anonymous_traversal_source::AnonymousTraversalSource;
 
@@ -76,12 +87,14 @@ use core::task::Poll;
 use futures::Stream;
 use std::pin::Pin;
 
+use futures::StreamExt;
 use pin_project_lite::pin_project;
-use tokio_stream::StreamExt;
 
 pin_project! {
     pub struct RemoteTraversalStream<SD: GraphSON, T> {
         phantom: PhantomData<T>,
+        // The value of the current traverser and how many more times it is to be yielded.
+        pending: Option<(GValue, i64)>,
         #[pin]
         stream: GResultSet<SD>,
     }
@@ -91,9 +104,24 @@ impl<SD: GraphSON, T> RemoteTraversalStream<SD, T> {
     pub fn new(stream: GResultSet<SD>) -> Self {
         RemoteTraversalStream {
             phantom: PhantomData,
+            pending: None,
             stream,
         }
     }
+
+    /// The meta and status attributes of the latest response received, see
+    /// [`GResultSet::metadata`].
+    pub fn metadata(&self) -> ResponseMetadata {
+        self.stream.metadata()
+    }
+
+    /// Yields each traverser once along with its bulk, instead of repeating its value.
+    pub fn traversers(self) -> RemoteTraverserStream<SD, T> {
+        RemoteTraverserStream {
+            phantom: PhantomData,
+            stream: self.stream,
+        }
+    }
 }
 
 impl<SD: GraphSON> RemoteTraversalStream<SD, crate::structure::Null> {
@@ -109,16 +137,119 @@ impl<SD: GraphSON> RemoteTraversalStream<SD, crate::structure::Null> {
 impl<SD: GraphSON, T: FromGValue> Stream for RemoteTraversalStream<SD, T> {
     type Item = GremlinResult<T>;
 
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this.pending.take() {
+                Some((value, bulk)) if bulk > 1 => {
+                    *this.pending = Some((value.clone(), bulk - 1));
+                    return Poll::Ready(Some(value.take::<T>()));
+                }
+                Some((value, _)) => return Poll::Ready(Some(value.take::<T>())),
+                None => {}
+            }
+
+            match futures::ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(Ok(item)) => {
+                    let (value, bulk) = traverser(item);
+                    if bulk > 0 {
+                        *this.pending = Some((value, bulk));
+                    }
+                }
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// The traversers of a remote traversal as `(value, bulk)` pairs, see
+    /// [`GraphTraversal::iter_traversers`].
+    pub struct RemoteTraverserStream<SD: GraphSON, T> {
+        phantom: PhantomData<T>,
+        #[pin]
+        stream: GResultSet<SD>,
+    }
+}
+
+impl<SD: GraphSON, T> RemoteTraverserStream<SD, T> {
+    /// The meta and status attributes of the latest response received, see
+    /// [`GResultSet::metadata`].
+    pub fn metadata(&self) -> ResponseMetadata {
+        self.stream.metadata()
+    }
+}
+
+impl<SD: GraphSON, T: FromGValue> Stream for RemoteTraverserStream<SD, T> {
+    type Item = GremlinResult<(T, i64)>;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
 
         let item = futures::ready!(this.stream.poll_next(cx));
 
         Poll::Ready(item.map(|e| {
-            e.expect("Failed to take an item from the result set")
-                .take::<Traverser>()
-                .expect("Failed to convert the item to a Traverser")
-                .take::<T>()
+            let (value, bulk) = traverser(e?);
+            Ok((value.take::<T>()?, bulk))
         }))
     }
 }
+
+/// Splits a result into its value and bulk. Results not wrapped in a traverser count once.
+fn traverser(item: GValue) -> (GValue, i64) {
+    match item {
+        GValue::Traverser(traverser) => traverser.into_parts(),
+        value => (value, 1),
+    }
+}
+
+/// The complete results of a traversal run in process, with the terminal operations over them
+/// shared by [`LocalTerminator`] and [`RecordingTerminator`].
+pub(crate) struct Results(Vec<GValue>);
+
+impl Results {
+    pub(crate) fn new(values: Vec<GValue>) -> Results {
+        Results(values)
+    }
+
+    pub(crate) fn has_next(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    pub(crate) fn to_list<T: FromGValue>(self) -> GremlinResult<Vec<T>> {
+        self.try_next_n(usize::MAX)
+    }
+
+    pub(crate) fn next<T: FromGValue>(self) -> GremlinResult<Option<T>> {
+        self.0.into_iter().next().map(T::from_gvalue).transpose()
+    }
+
+    pub(crate) fn one<T: FromGValue>(mut self) -> GremlinResult<T> {
+        match self.0.len() {
+            1 => T::from_gvalue(self.0.remove(0)),
+            n => Err(GremlinError::Generic(format!(
+                "one() expected a single result but the traversal returned {}",
+                n
+            ))),
+        }
+    }
+
+    pub(crate) fn try_next_n<T: FromGValue>(self, n: usize) -> GremlinResult<Vec<T>> {
+        self.0.into_iter().take(n).map(T::from_gvalue).collect()
+    }
+
+    pub(crate) fn iter_traversers<T: FromGValue>(
+        self,
+    ) -> GremlinResult<std::vec::IntoIter<(T, i64)>> {
+        self.0
+            .into_iter()
+            .map(|item| {
+                let (value, bulk) = traverser(item);
+                Ok((T::from_gvalue(value)?, bulk))
+            })
+            .collect::<GremlinResult<Vec<_>>>()
+            .map(Vec::into_iter)
+    }
+}
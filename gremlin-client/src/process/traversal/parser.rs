@@ -0,0 +1,990 @@
+//! Parses traversals written in gremlin-lang, the language-agnostic Gremlin grammar, into
+//! [`Bytecode`].
+//!
+//! The parser covers traversal sources, steps, nested anonymous traversals (with or without the
+//! `__.` prefix), literals (strings, numbers with their type suffixes, lists, maps, sets,
+//! `datetime()` and `UUID()`), predicates, enum tokens and strategies. Identifiers that are not
+//! part of the grammar are variables, which become [`Binding`]s of the values bound in the
+//! [`GremlinParser`].
+
+use crate::prelude::{GremlinError, GremlinResult};
+use crate::process::traversal::strategies::{class_of, TraversalStrategy};
+use crate::process::traversal::{Binding, Bytecode, Order, Scope};
+use crate::structure::{
+    Cardinality, Column, Direction, GKey, GValue, List, Map, Merge, Pop, Set, TextP, DT, P, T,
+};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Source instructions, which may only appear before the first step.
+const SOURCES: &[&str] = &[
+    "with",
+    "withBulk",
+    "withComputer",
+    "withPath",
+    "withSack",
+    "withSideEffect",
+    "withStrategies",
+    "withoutStrategies",
+];
+
+/// Terminal methods accepted (and dropped) at the end of a traversal.
+const TERMINALS: &[&str] = &[
+    "iterate",
+    "next",
+    "hasNext",
+    "toList",
+    "toSet",
+    "toBulkSet",
+    "tryNext",
+];
+
+const PREDICATES: &[&str] = &[
+    "eq", "neq", "lt", "lte", "gt", "gte", "inside", "outside", "between", "within", "without",
+    "not",
+];
+
+const TEXT_PREDICATES: &[&str] = &[
+    "containing",
+    "notContaining",
+    "startingWith",
+    "notStartingWith",
+    "endingWith",
+    "notEndingWith",
+    "regex",
+    "notRegex",
+];
+
+/// Parses a gremlin-lang traversal without variables.
+pub fn parse(query: &str) -> GremlinResult<Bytecode> {
+    GremlinParser::new().parse(query)
+}
+
+/// A gremlin-lang parser, holding the values bound to the variables of the queries it parses.
+#[derive(Debug, Default, Clone)]
+pub struct GremlinParser {
+    bindings: HashMap<String, GValue>,
+}
+
+impl GremlinParser {
+    pub fn new() -> GremlinParser {
+        Default::default()
+    }
+
+    /// Binds the variable `name` to `value`.
+    pub fn bind<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.bindings.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn parse(&self, query: &str) -> GremlinResult<Bytecode> {
+        let mut parser = Parser::new(query, &self.bindings, false)?;
+        parser.root()
+    }
+
+    /// The variables referenced by `query`, in order of first appearance, whether bound or not.
+    pub fn variables(&self, query: &str) -> GremlinResult<Vec<String>> {
+        let mut parser = Parser::new(query, &self.bindings, true)?;
+        parser.root()?;
+        Ok(parser.variables)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Number(String),
+    Dot,
+    Comma,
+    Colon,
+    Semi,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+}
+
+fn tokenize(query: &str) -> GremlinResult<Vec<(Token, usize)>> {
+    let chars = query.char_indices().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (at, c) = chars[i];
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+
+        let token = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '.' => Token::Dot,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            ';' => Token::Semi,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '\'' | '"' => {
+                let (string, end) = string(&chars, i)?;
+                tokens.push((Token::Str(string), at));
+                i = end;
+                continue;
+            }
+            '-' if next == Some('I') => {
+                let (ident, end) = ident(&chars, i + 1);
+                tokens.push((Token::Ident(format!("-{}", ident)), at));
+                i = end;
+                continue;
+            }
+            c if c.is_ascii_digit() || (c == '-' && next.map_or(false, |n| n.is_ascii_digit())) => {
+                let (number, end) = number(&chars, i);
+                tokens.push((Token::Number(number), at));
+                i = end;
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' || c == '$' => {
+                let (ident, end) = ident(&chars, i);
+                tokens.push((Token::Ident(ident), at));
+                i = end;
+                continue;
+            }
+            c => {
+                return Err(GremlinError::Parse(
+                    at,
+                    format!("unexpected character `{}`", c),
+                ))
+            }
+        };
+        tokens.push((token, at));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+fn ident(chars: &[(usize, char)], start: usize) -> (String, usize) {
+    let mut end = start;
+    while end < chars.len()
+        && (chars[end].1.is_alphanumeric() || chars[end].1 == '_' || chars[end].1 == '$')
+    {
+        end += 1;
+    }
+    (chars[start..end].iter().map(|(_, c)| c).collect(), end)
+}
+
+fn number(chars: &[(usize, char)], start: usize) -> (String, usize) {
+    let at = |i: usize| chars.get(i).map(|(_, c)| *c);
+    let mut end = start + 1;
+
+    while let Some(c) = at(end) {
+        match c {
+            c if c.is_ascii_digit() => end += 1,
+            '.' if at(end + 1).map_or(false, |n| n.is_ascii_digit()) => end += 1,
+            'e' | 'E' => {
+                end += 1;
+                if let Some('+') | Some('-') = at(end) {
+                    end += 1;
+                }
+            }
+            _ => break,
+        }
+    }
+    if let Some(c) = at(end) {
+        if "lLiIbBsSnNfFdDmM".contains(c) {
+            end += 1;
+        }
+    }
+    (chars[start..end].iter().map(|(_, c)| c).collect(), end)
+}
+
+fn string(chars: &[(usize, char)], start: usize) -> GremlinResult<(String, usize)> {
+    let (at, quote) = chars[start];
+    let mut out = String::new();
+    let mut i = start + 1;
+
+    while let Some(&(pos, c)) = chars.get(i) {
+        match c {
+            c if c == quote => return Ok((out, i + 1)),
+            '\\' => {
+                let escaped = chars
+                    .get(i + 1)
+                    .map(|(_, c)| *c)
+                    .ok_or_else(|| GremlinError::Parse(pos, String::from("unterminated escape")))?;
+                i += 2;
+                match escaped {
+                    'n' => out.push('\n'),
+                    'r' => out.push('\r'),
+                    't' => out.push('\t'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    'u' => {
+                        let hex = chars
+                            .get(i..i + 4)
+                            .map(|hex| hex.iter().map(|(_, c)| c).collect::<String>())
+                            .ok_or_else(|| {
+                                GremlinError::Parse(pos, String::from("truncated unicode escape"))
+                            })?;
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(std::char::from_u32)
+                            .ok_or_else(|| {
+                                GremlinError::Parse(
+                                    pos,
+                                    format!("invalid unicode escape `{}`", hex),
+                                )
+                            })?;
+                        out.push(c);
+                        i += 4;
+                    }
+                    c => out.push(c),
+                }
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    Err(GremlinError::Parse(at, String::from("unterminated string")))
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    len: usize,
+    bindings: &'a HashMap<String, GValue>,
+    lenient: bool,
+    variables: Vec<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(
+        query: &str,
+        bindings: &'a HashMap<String, GValue>,
+        lenient: bool,
+    ) -> GremlinResult<Parser<'a>> {
+        Ok(Parser {
+            tokens: tokenize(query)?,
+            pos: 0,
+            len: query.len(),
+            bindings,
+            lenient,
+            variables: vec![],
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset).map(|(t, _)| t)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.len, |(_, at)| *at)
+    }
+
+    fn error<R>(&self, message: String) -> GremlinResult<R> {
+        Err(GremlinError::Parse(self.offset(), message))
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token) -> GremlinResult<()> {
+        if self.eat(&token) {
+            Ok(())
+        } else {
+            self.error(format!("expected {:?}, found {}", token, self.found()))
+        }
+    }
+
+    fn found(&self) -> String {
+        match self.peek() {
+            Some(token) => format!("{:?}", token),
+            None => String::from("end of input"),
+        }
+    }
+
+    fn ident(&mut self) -> GremlinResult<String> {
+        match self.advance() {
+            Some(Token::Ident(name)) => Ok(name),
+            _ => {
+                self.pos -= 1;
+                self.error(format!("expected an identifier, found {}", self.found()))
+            }
+        }
+    }
+
+    /// `g.source()*.step()*` with optional trailing terminal methods and semicolon.
+    fn root(&mut self) -> GremlinResult<Bytecode> {
+        self.ident()?;
+
+        let mut bytecode = Bytecode::new();
+        let mut in_sources = true;
+
+        while self.eat(&Token::Dot) {
+            let name = self.ident()?;
+
+            if TERMINALS.contains(&name.as_str()) {
+                self.expect(Token::LParen)?;
+                self.expect(Token::RParen)?;
+                break;
+            }
+
+            let args = self.args()?;
+
+            in_sources = in_sources && SOURCES.contains(&name.as_str());
+            if in_sources {
+                self.source(&mut bytecode, name, args);
+            } else {
+                bytecode.add_step(name, args);
+            }
+        }
+
+        self.eat(&Token::Semi);
+        if self.peek().is_some() {
+            return self.error(format!("unexpected {}", self.found()));
+        }
+        Ok(bytecode)
+    }
+
+    fn source(&self, bytecode: &mut Bytecode, name: String, args: Vec<GValue>) {
+        match name.as_str() {
+            "withoutStrategies" => bytecode.add_source(
+                name,
+                args.into_iter()
+                    .map(|arg| match arg {
+                        GValue::Strategy(s) => GValue::Class(String::from(s.class())),
+                        other => other,
+                    })
+                    .collect(),
+            ),
+            _ => bytecode.add_source(name, args),
+        }
+    }
+
+    /// `step(args)(.step(args))*`, the body of an anonymous traversal.
+    fn chain(&mut self, first: String) -> GremlinResult<GValue> {
+        let mut bytecode = Bytecode::new();
+        let args = self.args()?;
+        bytecode.add_step(first, args);
+
+        while self.peek() == Some(&Token::Dot) {
+            self.pos += 1;
+            let name = self.ident()?;
+            let args = self.args()?;
+            bytecode.add_step(name, args);
+        }
+        Ok(GValue::Bytecode(bytecode))
+    }
+
+    fn args(&mut self) -> GremlinResult<Vec<GValue>> {
+        self.expect(Token::LParen)?;
+        let mut args = vec![];
+        if self.eat(&Token::RParen) {
+            return Ok(args);
+        }
+        loop {
+            args.push(self.value()?);
+            if self.eat(&Token::RParen) {
+                return Ok(args);
+            }
+            self.expect(Token::Comma)?;
+        }
+    }
+
+    fn value(&mut self) -> GremlinResult<GValue> {
+        let at = self.offset();
+
+        let value = match self.advance() {
+            Some(Token::Str(s)) => GValue::String(s),
+            Some(Token::Number(n)) => number_value(&n).map_err(|e| GremlinError::Parse(at, e))?,
+            Some(Token::LBracket) => self.collection()?,
+            Some(Token::LBrace) => {
+                let mut items = vec![];
+                if !self.eat(&Token::RBrace) {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(&Token::RBrace) {
+                            break;
+                        }
+                        self.expect(Token::Comma)?;
+                    }
+                }
+                let set: Set = items.into();
+                GValue::Set(set)
+            }
+            Some(Token::Ident(name)) => self.ident_value(name, at)?,
+            _ => {
+                self.pos -= 1;
+                return self.error(format!("expected a value, found {}", self.found()));
+            }
+        };
+
+        self.connectives(value)
+    }
+
+    /// `P.gt(1).and(P.lt(5))`: combines predicates with `and()` / `or()`.
+    fn connectives(&mut self, mut value: GValue) -> GremlinResult<GValue> {
+        while let GValue::P(_) | GValue::TextP(_) = value {
+            let connective = match (self.peek(), self.peek_at(1), self.peek_at(2)) {
+                (Some(Token::Dot), Some(Token::Ident(op)), Some(Token::LParen))
+                    if op == "and" || op == "or" =>
+                {
+                    op.clone()
+                }
+                _ => break,
+            };
+            self.pos += 3;
+            let other = self.value()?;
+            self.expect(Token::RParen)?;
+            value = GValue::P(P::new(
+                connective,
+                GValue::List(List::new(vec![value, other])),
+            ));
+        }
+        Ok(value)
+    }
+
+    /// A list `[a, b]` or a map `[k: v]`, after the opening bracket.
+    fn collection(&mut self) -> GremlinResult<GValue> {
+        if self.eat(&Token::RBracket) {
+            return Ok(GValue::List(List::new(vec![])));
+        }
+        if self.peek() == Some(&Token::Colon) && self.peek_at(1) == Some(&Token::RBracket) {
+            self.pos += 2;
+            return Ok(GValue::Map(Map::from(HashMap::<GKey, GValue>::new())));
+        }
+
+        let first = self.key_or_value()?;
+        if self.eat(&Token::Colon) {
+            let mut map = HashMap::new();
+            let mut key = first;
+            loop {
+                let at = self.offset();
+                let key = map_key(key).map_err(|e| GremlinError::Parse(at, e))?;
+                map.insert(key, self.value()?);
+                if self.eat(&Token::RBracket) {
+                    return Ok(GValue::Map(Map::from(map)));
+                }
+                self.expect(Token::Comma)?;
+                let next = self.key_or_value()?;
+                self.expect(Token::Colon)?;
+                key = next;
+            }
+        }
+
+        let mut items = vec![first];
+        while !self.eat(&Token::RBracket) {
+            self.expect(Token::Comma)?;
+            items.push(self.value()?);
+        }
+        Ok(GValue::List(List::new(items)))
+    }
+
+    /// Map keys may be bare identifiers (`[name: 'marko']`) or parenthesised (`[(T.id): 1]`).
+    fn key_or_value(&mut self) -> GremlinResult<GValue> {
+        match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Ident(name)), Some(Token::Colon)) => {
+                let key = GValue::String(name.clone());
+                self.pos += 1;
+                Ok(key)
+            }
+            (Some(Token::LParen), _) => {
+                self.pos += 1;
+                let key = self.value()?;
+                self.expect(Token::RParen)?;
+                Ok(key)
+            }
+            _ => self.value(),
+        }
+    }
+
+    fn ident_value(&mut self, name: String, at: usize) -> GremlinResult<GValue> {
+        let call = self.peek() == Some(&Token::LParen);
+
+        match name.as_str() {
+            "true" => return Ok(GValue::Bool(true)),
+            "false" => return Ok(GValue::Bool(false)),
+            "null" => return Ok(GValue::Null),
+            "NaN" => return Ok(GValue::Double(f64::NAN)),
+            "Infinity" => return Ok(GValue::Double(f64::INFINITY)),
+            "-Infinity" => return Ok(GValue::Double(f64::NEG_INFINITY)),
+            "new" => {
+                let name = self.ident()?;
+                let class = self.qualified_class(&name).unwrap_or(name);
+                return self.strategy(class);
+            }
+            "__" => {
+                self.expect(Token::Dot)?;
+                let first = self.ident()?;
+                return self.chain(first);
+            }
+            "datetime" if call => return self.datetime(),
+            "UUID" if call => {
+                let args = self.args()?;
+                return match args.as_slice() {
+                    [GValue::String(s)] => uuid::Uuid::from_str(s)
+                        .map(GValue::Uuid)
+                        .or_else(|e| self.error(format!("invalid UUID: {}", e))),
+                    _ => self.error(String::from("UUID() expects a string")),
+                };
+            }
+            "P" | "TextP" => {
+                self.expect(Token::Dot)?;
+                let operator = self.ident()?;
+                return self.predicate(&operator);
+            }
+            "VertexProperty" => {
+                self.expect(Token::Dot)?;
+                let class = self.ident()?;
+                return self.qualified_enum(&class);
+            }
+            _ => {}
+        }
+
+        if class_of(&name).is_some() {
+            return self.strategy(name);
+        }
+        if let Some(class) = self.qualified_class(&name) {
+            return self.strategy(class);
+        }
+        // Provider strategies, e.g. `JanusGraphStepStrategy`, are passed through by name.
+        if name.ends_with("Strategy") && name.starts_with(char::is_uppercase) {
+            return self.strategy(name);
+        }
+        if call && (PREDICATES.contains(&name.as_str()) || TEXT_PREDICATES.contains(&name.as_str()))
+        {
+            return self.predicate(&name);
+        }
+        if call {
+            return self.chain(name);
+        }
+        if self.peek() == Some(&Token::Dot) && enum_value(&name, "").is_some() {
+            return self.qualified_enum(&name);
+        }
+        if let Some(value) = bare_enum(&name) {
+            return Ok(value);
+        }
+        self.variable(name, at)
+    }
+
+    fn qualified_enum(&mut self, class: &str) -> GremlinResult<GValue> {
+        self.expect(Token::Dot)?;
+        let name = self.ident()?;
+        match enum_value(class, &name) {
+            Some(Some(value)) => Ok(value),
+            _ => self.error(format!("unknown enum value `{}.{}`", class, name)),
+        }
+    }
+
+    fn variable(&mut self, name: String, at: usize) -> GremlinResult<GValue> {
+        if !self.variables.contains(&name) {
+            self.variables.push(name.clone());
+        }
+        match self.bindings.get(&name) {
+            Some(value) => Ok(GValue::Binding(Binding::new(name, value.clone()))),
+            None if self.lenient => Ok(GValue::Null),
+            None => Err(GremlinError::Parse(
+                at,
+                format!("unbound variable `{}`", name),
+            )),
+        }
+    }
+
+    fn predicate(&mut self, operator: &str) -> GremlinResult<GValue> {
+        let mut args = self.args()?;
+
+        if TEXT_PREDICATES.contains(&operator) {
+            return match args.len() {
+                1 => Ok(GValue::TextP(TextP::new(operator, args.remove(0)))),
+                _ => self.error(format!("{}() expects one argument", operator)),
+            };
+        }
+
+        let value = match (operator, args.len()) {
+            ("within", 1) | ("without", 1) => match args.remove(0) {
+                GValue::List(list) => GValue::List(list),
+                GValue::Binding(binding) => GValue::Binding(binding),
+                other => GValue::List(List::new(vec![other])),
+            },
+            ("within", _) | ("without", _) => GValue::List(List::new(args)),
+            ("between", 2) | ("inside", 2) | ("outside", 2) => GValue::List(List::new(args)),
+            (op, 1) if PREDICATES.contains(&op) => args.remove(0),
+            (op, _) if PREDICATES.contains(&op) => {
+                return self.error(format!("wrong number of arguments for {}()", op))
+            }
+            (op, _) => return self.error(format!("unknown predicate `{}`", op)),
+        };
+        Ok(GValue::P(P::new(operator, value)))
+    }
+
+    /// Consumes the rest of a fully qualified class name such as
+    /// `org.janusgraph.graphdb.tinkerpop.optimize.strategy.JanusGraphStepStrategy`, given its
+    /// first segment. Nothing is consumed unless the name ends in `Strategy`.
+    fn qualified_class(&mut self, first: &str) -> Option<String> {
+        let mut class = String::from(first);
+        let mut offset = 0;
+        while let (Some(Token::Dot), Some(Token::Ident(part))) =
+            (self.peek_at(offset), self.peek_at(offset + 1))
+        {
+            class.push('.');
+            class.push_str(part);
+            offset += 2;
+        }
+        if offset == 0 || !class.ends_with("Strategy") {
+            return None;
+        }
+        self.pos += offset;
+        Some(class)
+    }
+
+    /// `[new] NameStrategy[(key: value, ...)]`. Strategies the client doesn't know are passed
+    /// through with the given name, which may be a fully qualified class name.
+    fn strategy(&mut self, name: String) -> GremlinResult<GValue> {
+        let class = class_of(&name).unwrap_or(name);
+        let mut strategy = TraversalStrategy::new(class);
+
+        if self.eat(&Token::LParen) && !self.eat(&Token::RParen) {
+            loop {
+                let key = self.ident()?;
+                self.expect(Token::Colon)?;
+                strategy = strategy.config(key, self.value()?);
+                if self.eat(&Token::RParen) {
+                    break;
+                }
+                self.expect(Token::Comma)?;
+            }
+        }
+        Ok(GValue::Strategy(strategy))
+    }
+
+    fn datetime(&mut self) -> GremlinResult<GValue> {
+        let args = self.args()?;
+        let text = match args.as_slice() {
+            [GValue::String(s)] => s.clone(),
+            _ => return self.error(String::from("datetime() expects a string")),
+        };
+
+        let parsed = DateTime::parse_from_rfc3339(&text)
+            .map(|d| d.with_timezone(&Utc))
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .map(|d| Utc.from_utc_datetime(&d))
+            });
+
+        match parsed {
+            Some(date) => Ok(GValue::Date(date)),
+            None => self.error(format!("invalid datetime `{}`", text)),
+        }
+    }
+}
+
+fn number_value(text: &str) -> Result<GValue, String> {
+    let (body, suffix) = match text.chars().last() {
+        Some(c) if c.is_ascii_alphabetic() => {
+            (&text[..text.len() - 1], Some(c.to_ascii_lowercase()))
+        }
+        _ => (text, None),
+    };
+    let is_float = body.contains(|c| c == '.' || c == 'e' || c == 'E');
+    let invalid = |_| format!("invalid number `{}`", text);
+
+    match suffix {
+        Some('l') | Some('n') => body.parse::<i64>().map(GValue::Int64).map_err(invalid),
+        Some('i') | Some('b') | Some('s') => {
+            body.parse::<i32>().map(GValue::Int32).map_err(invalid)
+        }
+        Some('f') => body.parse::<f32>().map(GValue::Float).map_err(invalid),
+        Some('d') | Some('m') => body.parse::<f64>().map(GValue::Double).map_err(invalid),
+        _ if is_float => body.parse::<f64>().map(GValue::Double).map_err(invalid),
+        _ => match body.parse::<i32>() {
+            Ok(v) => Ok(GValue::Int32(v)),
+            Err(_) => body.parse::<i64>().map(GValue::Int64).map_err(invalid),
+        },
+    }
+}
+
+fn map_key(key: GValue) -> Result<GKey, String> {
+    match key {
+        GValue::String(s) => Ok(GKey::String(s)),
+        GValue::T(t) => Ok(GKey::T(t)),
+        GValue::Direction(d) => Ok(GKey::Direction(d)),
+        GValue::Token(t) => Ok(GKey::Token(t)),
+        other => Err(format!("{:?} cannot be a map key", other)),
+    }
+}
+
+/// `Some(None)` when `class` is a known enum but `name` isn't one of its values.
+fn enum_value(class: &str, name: &str) -> Option<Option<GValue>> {
+    let value = match class {
+        "T" => match name {
+            "id" => Some(T::Id.into()),
+            "label" => Some(T::Label.into()),
+            "key" => Some(T::Key.into()),
+            "value" => Some(T::Value.into()),
+            _ => None,
+        },
+        "Order" => match name {
+            "asc" | "incr" => Some(GValue::Order(Order::Asc)),
+            "desc" | "decr" => Some(GValue::Order(Order::Desc)),
+            "shuffle" => Some(GValue::Order(Order::Shuffle)),
+            _ => None,
+        },
+        "Scope" => match name {
+            "global" => Some(GValue::Scope(Scope::Global)),
+            "local" => Some(GValue::Scope(Scope::Local)),
+            _ => None,
+        },
+        "Column" => match name {
+            "keys" => Some(GValue::Column(Column::Keys)),
+            "values" => Some(GValue::Column(Column::Values)),
+            _ => None,
+        },
+        "Direction" => match name {
+            "OUT" => Some(GValue::Direction(Direction::Out)),
+            "IN" => Some(GValue::Direction(Direction::In)),
+            "from" => Some(GValue::Direction(Direction::From)),
+            "to" => Some(GValue::Direction(Direction::To)),
+            _ => None,
+        },
+        "Cardinality" => match name {
+            "single" => Some(GValue::Cardinality(Cardinality::Single)),
+            "list" => Some(GValue::Cardinality(Cardinality::List)),
+            "set" => Some(GValue::Cardinality(Cardinality::Set)),
+            _ => None,
+        },
+        "Pop" => match name {
+            "first" => Some(GValue::Pop(Pop::First)),
+            "last" => Some(GValue::Pop(Pop::Last)),
+            "all" => Some(GValue::Pop(Pop::All)),
+            "mixed" => Some(GValue::Pop(Pop::Mixed)),
+            _ => None,
+        },
+        "Merge" => match name {
+            "onCreate" => Some(GValue::Merge(Merge::OnCreate)),
+            "onMatch" => Some(GValue::Merge(Merge::OnMatch)),
+            "outV" => Some(GValue::Merge(Merge::OutV)),
+            "inV" => Some(GValue::Merge(Merge::InV)),
+            _ => None,
+        },
+        "DT" => match name {
+            "second" => Some(GValue::DT(DT::Second)),
+            "minute" => Some(GValue::DT(DT::Minute)),
+            "hour" => Some(GValue::DT(DT::Hour)),
+            "day" => Some(GValue::DT(DT::Day)),
+            _ => None,
+        },
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Enum values may be used unqualified, e.g. `by('age', desc)`.
+fn bare_enum(name: &str) -> Option<GValue> {
+    [
+        "T",
+        "Order",
+        "Scope",
+        "Column",
+        "Direction",
+        "Cardinality",
+        "Pop",
+        "Merge",
+        "DT",
+    ]
+    .iter()
+    .filter(|class| **class != "Direction" || name == "OUT" || name == "IN")
+    .find_map(|class| enum_value(class, name).flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, GremlinParser};
+    use crate::prelude::GremlinError;
+    use crate::process::traversal::strategies::{PartitionStrategy, ReadOnlyStrategy};
+    use crate::process::traversal::{traversal, Binding, Bytecode, Dialect, Order, Translator, __};
+    use crate::structure::{Cardinality, GValue, TextP, P, T};
+
+    #[test]
+    fn parse_steps() {
+        let g = traversal().empty();
+
+        let expected = g
+            .v(1)
+            .out("knows")
+            .has(("age", P::gt(30)))
+            .has(("name", TextP::containing("ar\"k")))
+            .where_(__.out("created").values("lang"))
+            .order(())
+            .by(("age", Order::Desc))
+            .values("name");
+
+        let parsed = parse(
+            "g.V(1).out('knows').has(\"age\", gt(30)).has('name', TextP.containing('ar\"k'))\
+             .where(out('created').values('lang')).order(Scope.global).by('age', desc)\
+             .values('name').toList();",
+        )
+        .unwrap();
+
+        assert_eq!(expected.bytecode(), &parsed);
+    }
+
+    #[test]
+    fn parse_translated() {
+        let g = traversal()
+            .empty()
+            .with_strategies(vec![PartitionStrategy::new("_p")
+                .write_partition("a")
+                .read_partition("a")])
+            .without_strategies(vec![ReadOnlyStrategy::new()]);
+
+        let t = g
+            .add_v("person")
+            .property_with_cardinality(Cardinality::Single, "age", 30i64)
+            .property(T::Label, "x")
+            .property("weight", 0.5f32)
+            .has(("weight", P::within(vec![0.5, 1.0])))
+            .where_(__.values("age").is(P::gt(1)));
+
+        let text = Translator::default()
+            .dialect(Dialect::GremlinLang)
+            .translate(t.bytecode())
+            .unwrap();
+
+        assert_eq!(t.bytecode(), &parse(&text).unwrap());
+    }
+
+    #[test]
+    fn parse_literals() {
+        let parsed = parse(
+            "g.inject([1, 2L, 3.5, -4f, 'a'], [name: 'marko', (T.id): 1], [:], {true, null})",
+        )
+        .unwrap();
+
+        let args = parsed.steps()[0].args();
+
+        assert_eq!(
+            &GValue::List(
+                vec![
+                    GValue::Int32(1),
+                    GValue::Int64(2),
+                    GValue::Double(3.5),
+                    GValue::Float(-4.0),
+                    GValue::from("a"),
+                ]
+                .into()
+            ),
+            &args[0]
+        );
+        match &args[1] {
+            GValue::Map(map) => {
+                assert_eq!(Some(&GValue::from("marko")), map.get("name"));
+                assert_eq!(Some(&GValue::Int32(1)), map.get(T::Id));
+            }
+            other => panic!("expected a map, got {:?}", other),
+        }
+        assert_eq!(4, args.len());
+    }
+
+    #[test]
+    fn parse_variables() {
+        let query = "g.V(vid).has('age', gt(minAge)).out(edgeLabel, vid)";
+
+        assert_eq!(
+            vec!["vid", "minAge", "edgeLabel"],
+            GremlinParser::new().variables(query).unwrap()
+        );
+
+        match parse(query) {
+            Err(GremlinError::Parse(offset, _)) => assert_eq!(4, offset),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        let parsed = GremlinParser::new()
+            .bind("vid", 1)
+            .bind("minAge", 30)
+            .parse("g.V(vid).has('age', gt(minAge))")
+            .unwrap();
+
+        let mut expected = Bytecode::new();
+        expected.add_step(String::from("V"), vec![Binding::new("vid", 1).into()]);
+        expected.add_step(
+            String::from("has"),
+            vec!["age".into(), P::gt(Binding::new("minAge", 30)).into()],
+        );
+        assert_eq!(expected, parsed);
+        assert_eq!(Some(&GValue::Int32(30)), parsed.bindings().get("minAge"));
+
+        let parsed = GremlinParser::new()
+            .bind("nameStrategy", "marko")
+            .parse("g.V().has('name', nameStrategy)")
+            .unwrap();
+        assert_eq!(
+            vec!["name".into(), Binding::new("nameStrategy", "marko").into()],
+            *parsed.steps()[1].args()
+        );
+    }
+
+    #[test]
+    fn parse_not_and_provider_strategies() {
+        let parsed = parse("g.V().has('age', P.not(P.eq(1)))").unwrap();
+        assert_eq!(
+            vec!["age".into(), P::new("not", P::eq(1).into()).into()],
+            *parsed.steps()[1].args()
+        );
+
+        let janus = "org.janusgraph.graphdb.tinkerpop.optimize.strategy.JanusGraphStepStrategy";
+        for query in &[
+            String::from("g.withoutStrategies(JanusGraphStepStrategy).V()"),
+            format!("g.withoutStrategies({}).V()", janus),
+            format!("g.withStrategies(new {}()).V()", janus),
+        ] {
+            let parsed = parse(query).unwrap();
+            let class = match &parsed.sources()[0].args()[0] {
+                GValue::Class(class) => class.clone(),
+                GValue::Strategy(strategy) => String::from(strategy.class()),
+                other => panic!("expected a strategy, got {:?}", other),
+            };
+            assert!(janus.ends_with(&class));
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse("g.V(").is_err());
+        assert!(parse("g.V().has('name', 'marko'").is_err());
+        assert!(parse("g.V() extra").is_err());
+        assert!(parse("g.V('unterminated)").is_err());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::conversion::FromGValue;
+use crate::prelude::{GremlinError, GremlinResult};
+use crate::process::traversal::{Bytecode, GraphTraversal, Results, Terminator, Translator};
+use crate::structure::{GValue, TraversalExplanation, TraversalMetrics};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A terminator for unit tests: it records the bytecode of every executed traversal and replies
+/// with canned results, in the order they were queued.
+///
+/// Clones share the same recording and replies, so the terminator can be kept around after
+/// handing it to a [`GraphTraversalSource`](crate::process::traversal::GraphTraversalSource).
+/// Once the queue is exhausted every traversal yields no results.
+#[derive(Clone, Default)]
+pub struct RecordingTerminator {
+    state: Arc<Mutex<Recording>>,
+}
+
+#[derive(Default)]
+struct Recording {
+    submitted: Vec<Bytecode>,
+    terminals: Vec<&'static str>,
+    replies: VecDeque<GremlinResult<Vec<GValue>>>,
+}
+
+impl RecordingTerminator {
+    pub fn new() -> RecordingTerminator {
+        Default::default()
+    }
+
+    /// Queues the results of the next traversal.
+    pub fn reply<I, V>(&self, results: I) -> &Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<GValue>,
+    {
+        let results = results.into_iter().map(Into::into).collect();
+        self.lock().replies.push_back(Ok(results));
+        self
+    }
+
+    /// Queues an error as the outcome of the next traversal.
+    pub fn reply_error(&self, error: GremlinError) -> &Self {
+        self.lock().replies.push_back(Err(error));
+        self
+    }
+
+    /// The bytecode of every traversal executed so far, oldest first.
+    pub fn submitted(&self) -> Vec<Bytecode> {
+        self.lock().submitted.clone()
+    }
+
+    /// The bytecode of the most recently executed traversal.
+    pub fn last(&self) -> Option<Bytecode> {
+        self.lock().submitted.last().cloned()
+    }
+
+    /// The terminal step that executed each traversal of [`submitted`](Self::submitted), such as
+    /// `toList`, `next` or `profile`.
+    pub fn terminals(&self) -> Vec<&'static str> {
+        self.lock().terminals.clone()
+    }
+
+    /// Forgets the recorded traversals and any replies still queued.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.submitted.clear();
+        state.terminals.clear();
+        state.replies.clear();
+    }
+
+    /// Panics unless exactly `expected` was executed, in that order.
+    pub fn assert_submitted(&self, expected: &[Bytecode]) {
+        assert_eq!(expected, self.submitted().as_slice());
+    }
+
+    /// Panics unless the most recent traversal matches `expected`.
+    pub fn assert_last(&self, expected: &Bytecode) {
+        assert_eq!(Some(expected), self.last().as_ref());
+    }
+
+    /// Panics unless the most recent traversal translates to `expected` as a Gremlin-Groovy
+    /// script on the `g` traversal source, e.g. `g.V().hasLabel('person').count()`.
+    pub fn assert_last_gremlin(&self, expected: &str) {
+        let last = self
+            .last()
+            .map(|bytecode| Translator::new("g").translate(&bytecode).unwrap());
+        assert_eq!(Some(expected), last.as_deref());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Recording> {
+        // A failed assertion while holding the lock must not hide the recording from the next
+        // one, so a poisoned lock is still used.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn execute(&self, bytecode: &Bytecode, terminal: &'static str) -> GremlinResult<Vec<GValue>> {
+        let mut state = self.lock();
+        state.submitted.push(bytecode.clone());
+        state.terminals.push(terminal);
+        state.replies.pop_front().unwrap_or_else(|| Ok(vec![]))
+    }
+
+    fn results(&self, bytecode: &Bytecode, terminal: &'static str) -> GremlinResult<Results> {
+        Ok(Results::new(self.execute(bytecode, terminal)?))
+    }
+
+    fn single<T: FromGValue>(&self, bytecode: &Bytecode, step: &'static str) -> GremlinResult<T> {
+        self.results(bytecode, step)?
+            .next()?
+            .ok_or_else(|| GremlinError::Generic(format!("No reply queued for {}()", step)))
+    }
+}
+
+impl<T: FromGValue> Terminator<T> for RecordingTerminator {
+    type List = GremlinResult<Vec<T>>;
+    type Next = GremlinResult<Option<T>>;
+    type One = GremlinResult<T>;
+    type NextN = GremlinResult<Vec<T>>;
+    type HasNext = GremlinResult<bool>;
+    type Iter = GremlinResult<std::vec::IntoIter<T>>;
+    type Profile = GremlinResult<TraversalMetrics>;
+    type Explain = GremlinResult<TraversalExplanation>;
+    type Traversers = GremlinResult<std::vec::IntoIter<(T, i64)>>;
+
+    fn to_list<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::List
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode(), "toList")?.to_list()
+    }
+
+    fn next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Next
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode(), "next")?.next()
+    }
+
+    fn one<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::One
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode(), "one")?.one()
+    }
+
+    fn try_next_n<S, E>(&self, traversal: &GraphTraversal<S, T, E>, n: usize) -> Self::NextN
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode(), "tryNextN")?
+            .try_next_n(n)
+    }
+
+    fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
+    where
+        E: Terminator<T>,
+    {
+        Ok(self.results(traversal.bytecode(), "hasNext")?.has_next())
+    }
+
+    fn iter<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Iter
+    where
+        E: Terminator<T>,
+    {
+        Ok(self
+            .results(traversal.bytecode(), "iter")?
+            .to_list()?
+            .into_iter())
+    }
+
+    fn profile<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Profile
+    where
+        E: Terminator<T>,
+    {
+        self.single(traversal.bytecode(), "profile")
+    }
+
+    fn explain<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Explain
+    where
+        E: Terminator<T>,
+    {
+        self.single(traversal.bytecode(), "explain")
+    }
+
+    fn iter_traversers<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Traversers
+    where
+        E: Terminator<T>,
+    {
+        self.results(traversal.bytecode(), "iterTraversers")?
+            .iter_traversers()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecordingTerminator;
+    use crate::prelude::GremlinError;
+    use crate::process::traversal::{Bytecode, GraphTraversalSource};
+    use crate::structure::GValue;
+
+    #[test]
+    fn recording_replies_in_order() {
+        let terminator = RecordingTerminator::new();
+        terminator
+            .reply(vec![3_i64])
+            .reply_error(GremlinError::Generic(String::from("boom")));
+        let g = GraphTraversalSource::new(terminator.clone());
+
+        let count = g.v(()).has_label("person").count().next().unwrap();
+        assert_eq!(Some(3), count);
+        terminator.assert_last_gremlin("g.V().hasLabel('person').count()");
+
+        let names = g.v(1).values("name").to_list();
+        assert!(names.is_err());
+
+        let empty: Vec<GValue> = g.v(()).values("age").to_list().unwrap();
+        assert!(empty.is_empty());
+
+        let mut expected = Bytecode::new();
+        expected.add_step(String::from("V"), vec![1.into()]);
+        expected.add_step(String::from("values"), vec!["name".into()]);
+        assert_eq!(3, terminator.submitted().len());
+        assert_eq!(expected, terminator.submitted()[1]);
+
+        terminator.clear();
+        terminator.assert_submitted(&[]);
+    }
+
+    #[test]
+    fn recording_profile_keeps_the_bytecode() {
+        let terminator = RecordingTerminator::new();
+        let g = GraphTraversalSource::new(terminator.clone());
+
+        assert!(g.v(()).out("knows").profile().is_err());
+        terminator.assert_last_gremlin("g.V().out('knows')");
+
+        let _: Vec<GValue> = g.v(()).to_list().unwrap();
+        assert_eq!(vec!["profile", "toList"], terminator.terminals());
+    }
+}
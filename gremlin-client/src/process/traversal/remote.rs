@@ -1,6 +1,7 @@
 use crate::conversion::FromGValue;
-use crate::prelude::{GraphSON, GremlinClient, GremlinResult};
-use crate::process::traversal::{GraphTraversal, GraphTraversalSource};
+use crate::prelude::{GraphSON, GremlinClient, GremlinError, GremlinResult};
+use crate::process::traversal::{GraphTraversal, GraphTraversalSource, LocalTerminator};
+use crate::structure::TinkerGraph;
 
 pub fn traversal() -> RemoteTraversalSource {
     RemoteTraversalSource {}
@@ -16,11 +17,19 @@ impl RemoteTraversalSource {
         GraphTraversalSource::<MockTerminator>::new(MockTerminator {}).with_remote(client)
     }
 
+    /// Runs traversals in process against `graph`, see [`LocalTerminator`].
+    pub fn with_local(&self, graph: TinkerGraph) -> GraphTraversalSource<LocalTerminator> {
+        GraphTraversalSource::<MockTerminator>::new(MockTerminator {}).with_local(graph)
+    }
+
     pub fn empty(&self) -> GraphTraversalSource<MockTerminator> {
         GraphTraversalSource::<MockTerminator>::new(MockTerminator {})
     }
 }
 
+/// Builds traversals without running them. Every terminal step fails, since there is no graph
+/// to run against; see [`RecordingTerminator`](crate::process::traversal::RecordingTerminator)
+/// for canned results.
 #[derive(Clone)]
 pub struct MockTerminator {}
 
@@ -37,44 +46,96 @@ impl MockTerminator {
 }
 
 impl<T: FromGValue> Terminator<T> for MockTerminator {
-    type List = ();
-    type Next = ();
-    type HasNext = ();
-    type Iter = ();
+    type List = GremlinResult<Vec<T>>;
+    type Next = GremlinResult<Option<T>>;
+    type One = GremlinResult<T>;
+    type NextN = GremlinResult<Vec<T>>;
+    type HasNext = GremlinResult<bool>;
+    type Iter = GremlinResult<std::vec::IntoIter<T>>;
+    type Profile = GremlinResult<TraversalMetrics>;
+    type Explain = GremlinResult<TraversalExplanation>;
+    type Traversers = GremlinResult<std::vec::IntoIter<(T, i64)>>;
 
     fn to_list<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::List
     where
         E: Terminator<T>,
     {
-        unimplemented!()
+        Err(not_connected())
     }
 
     fn next<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Next
     where
         E: Terminator<T>,
     {
-        unimplemented!()
+        Err(not_connected())
+    }
+
+    fn one<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::One
+    where
+        E: Terminator<T>,
+    {
+        Err(not_connected())
+    }
+
+    fn try_next_n<S, E>(&self, _traversal: &GraphTraversal<S, T, E>, _n: usize) -> Self::NextN
+    where
+        E: Terminator<T>,
+    {
+        Err(not_connected())
     }
 
     fn has_next<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
     where
         E: Terminator<T>,
     {
-        unimplemented!()
+        Err(not_connected())
     }
 
     fn iter<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Iter
     where
         E: Terminator<T>,
     {
-        unimplemented!()
+        Err(not_connected())
+    }
+
+    fn profile<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Profile
+    where
+        E: Terminator<T>,
+    {
+        Err(not_connected())
+    }
+
+    fn explain<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Explain
+    where
+        E: Terminator<T>,
+    {
+        Err(not_connected())
+    }
+
+    fn iter_traversers<S, E>(&self, _traversal: &GraphTraversal<S, T, E>) -> Self::Traversers
+    where
+        E: Terminator<T>,
+    {
+        Err(not_connected())
     }
 }
+
+fn not_connected() -> GremlinError {
+    GremlinError::Generic(String::from(
+        "Traversal source is not connected, use with_remote or with_local",
+    ))
+}
+
 pub trait Terminator<T: FromGValue>: Clone {
     type List;
     type Next;
+    type One;
+    type NextN;
     type HasNext;
     type Iter;
+    type Profile;
+    type Explain;
+    type Traversers;
 
     fn to_list<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::List
     where
@@ -84,6 +145,14 @@ pub trait Terminator<T: FromGValue>: Clone {
     where
         E: Terminator<T>;
 
+    fn one<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::One
+    where
+        E: Terminator<T>;
+
+    fn try_next_n<S, E>(&self, traversal: &GraphTraversal<S, T, E>, n: usize) -> Self::NextN
+    where
+        E: Terminator<T>;
+
     fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
     where
         E: Terminator<T>;
@@ -91,6 +160,22 @@ pub trait Terminator<T: FromGValue>: Clone {
     fn iter<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Iter
     where
         E: Terminator<T>;
+
+    /// Runs the traversal with a trailing `profile()` step and returns its metrics.
+    fn profile<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Profile
+    where
+        E: Terminator<T>;
+
+    /// Asks the server how its strategies rewrite the traversal, without running it.
+    fn explain<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Explain
+    where
+        E: Terminator<T>;
+
+    /// Runs the traversal and yields each traverser once as a `(value, bulk)` pair, rather
+    /// than repeating its value `bulk` times.
+    fn iter_traversers<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Traversers
+    where
+        E: Terminator<T>;
 }
 
 // #[derive(Clone)]
@@ -143,7 +228,11 @@ pub trait Terminator<T: FromGValue>: Clone {
 //     }
 // }
 
-use crate::process::traversal::RemoteTraversalStream;
+use crate::process::traversal::step::validate_math;
+use crate::process::traversal::{
+    Bytecode, RemoteTraversalStream, RemoteTraverserStream, Translator,
+};
+use crate::structure::{TraversalExplanation, TraversalMetrics};
 use futures::future::{BoxFuture, FutureExt};
 use futures::StreamExt;
 
@@ -161,8 +250,13 @@ impl<SD: GraphSON> AsyncTerminator<SD> {
 impl<SD: GraphSON, T: FromGValue + Send + 'static> Terminator<T> for AsyncTerminator<SD> {
     type List = BoxFuture<'static, GremlinResult<Vec<T>>>;
     type Next = BoxFuture<'static, GremlinResult<Option<T>>>;
+    type One = BoxFuture<'static, GremlinResult<T>>;
+    type NextN = BoxFuture<'static, GremlinResult<Vec<T>>>;
     type HasNext = BoxFuture<'static, GremlinResult<bool>>;
     type Iter = BoxFuture<'static, GremlinResult<RemoteTraversalStream<SD, T>>>;
+    type Profile = BoxFuture<'static, GremlinResult<TraversalMetrics>>;
+    type Explain = BoxFuture<'static, GremlinResult<TraversalExplanation>>;
+    type Traversers = BoxFuture<'static, GremlinResult<RemoteTraverserStream<SD, T>>>;
 
     fn to_list<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::List
     where
@@ -193,19 +287,35 @@ impl<SD: GraphSON, T: FromGValue + Send + 'static> Terminator<T> for AsyncTermin
     {
         let iter = self.iter(traversal);
 
+        async move { iter.await?.next().await.transpose() }.boxed()
+    }
+
+    fn one<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::One
+    where
+        E: Terminator<T>,
+    {
+        let iter = self.iter(traversal);
+
         async move {
             let mut stream = iter.await?;
 
-            let mut vec = vec![];
-            while let Some(item) = stream.next().await {
-                vec.push(item?);
+            match (
+                stream.next().await.transpose()?,
+                stream.next().await.transpose()?,
+            ) {
+                (Some(item), None) => Ok(item),
+                (None, _) => Err(GremlinError::Generic(String::from(
+                    "one() expected a result but the traversal returned none",
+                ))),
+                (Some(_), Some(_)) => Err(GremlinError::Generic(String::from(
+                    "one() expected a single result but the traversal returned more",
+                ))),
             }
-            Ok(vec.pop())
         }
         .boxed()
     }
 
-    fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
+    fn try_next_n<S, E>(&self, traversal: &GraphTraversal<S, T, E>, n: usize) -> Self::NextN
     where
         E: Terminator<T>,
     {
@@ -214,15 +324,27 @@ impl<SD: GraphSON, T: FromGValue + Send + 'static> Terminator<T> for AsyncTermin
         async move {
             let mut stream = iter.await?;
 
-            let mut vec = vec![];
-            while let Some(item) = stream.next().await {
-                vec.push(item?);
+            let mut vec = Vec::with_capacity(n);
+            while vec.len() < n {
+                match stream.next().await {
+                    Some(item) => vec.push(item?),
+                    None => break,
+                }
             }
-            Ok(vec.len() > 0)
+            Ok(vec)
         }
         .boxed()
     }
 
+    fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
+    where
+        E: Terminator<T>,
+    {
+        let iter = self.iter(traversal);
+
+        async move { Ok(iter.await?.next().await.transpose()?.is_some()) }.boxed()
+    }
+
     fn iter<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Iter
     where
         E: Terminator<T>,
@@ -231,10 +353,87 @@ impl<SD: GraphSON, T: FromGValue + Send + 'static> Terminator<T> for AsyncTermin
         let bytecode = traversal.bytecode().clone();
 
         async move {
+            validate_math(&bytecode)?;
+
             let stream = client.submit_traversal(&bytecode).await?;
 
             Ok(RemoteTraversalStream::new(stream))
         }
         .boxed()
     }
+
+    fn profile<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Profile
+    where
+        E: Terminator<T>,
+    {
+        self.single(traversal.bytecode(), "profile")
+    }
+
+    fn explain<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Explain
+    where
+        E: Terminator<T>,
+    {
+        let client = self.client.clone();
+        let bytecode = traversal.bytecode().clone();
+
+        async move {
+            validate_math(&bytecode)?;
+
+            // explain() is a terminal method rather than a step, so the server only takes it
+            // as part of a script.
+            let script = format!("{}.explain()", Translator::default().translate(&bytecode)?);
+            let mut results = client.eval(script, bytecode.bindings()).await?;
+
+            results
+                .next()
+                .await
+                .unwrap_or_else(|| {
+                    Err(GremlinError::Generic(String::from(
+                        "explain() did not return a result",
+                    )))
+                })?
+                .take::<TraversalExplanation>()
+        }
+        .boxed()
+    }
+
+    fn iter_traversers<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Traversers
+    where
+        E: Terminator<T>,
+    {
+        let iter = self.iter(traversal);
+
+        async move { Ok(iter.await?.traversers()) }.boxed()
+    }
+}
+
+impl<SD: GraphSON> AsyncTerminator<SD> {
+    /// Submits `bytecode` followed by `step` and takes the single result it yields.
+    fn single<R>(&self, bytecode: &Bytecode, step: &str) -> BoxFuture<'static, GremlinResult<R>>
+    where
+        R: FromGValue + Send + 'static,
+    {
+        let client = self.client.clone();
+        let mut bytecode = bytecode.clone();
+        let step = String::from(step);
+
+        bytecode.add_step(step.clone(), vec![]);
+
+        async move {
+            validate_math(&bytecode)?;
+
+            let stream = client.submit_traversal(&bytecode).await?;
+
+            RemoteTraversalStream::<SD, R>::new(stream)
+                .next()
+                .await
+                .unwrap_or_else(|| {
+                    Err(GremlinError::Generic(format!(
+                        "{}() did not return a result",
+                        step
+                    )))
+                })
+        }
+        .boxed()
+    }
 }
@@ -1,5 +1,5 @@
 use crate::process::traversal::{Order, TraversalBuilder};
-use crate::structure::{Column, GValue, T};
+use crate::structure::{Column, GValue, Lambda, T};
 
 pub struct ByStep {
     params: Vec<GValue>,
@@ -70,3 +70,15 @@ impl From<TraversalBuilder> for ByStep {
         ByStep::new(vec![param.bytecode.into()])
     }
 }
+
+impl From<Lambda> for ByStep {
+    fn from(param: Lambda) -> Self {
+        ByStep::new(vec![param.into()])
+    }
+}
+
+impl From<(&str, Lambda)> for ByStep {
+    fn from(param: (&str, Lambda)) -> Self {
+        ByStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
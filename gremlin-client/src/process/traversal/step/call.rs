@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use crate::prelude::{traversal::TraversalBuilder, GKey, GValue, Map};
+
+/// Arguments of the `call()` step: the service name, optionally followed by a map of static
+/// parameters and/or a child traversal producing dynamic ones.
+pub struct CallStep {
+    params: Vec<GValue>,
+}
+
+impl CallStep {
+    fn new(params: Vec<GValue>) -> Self {
+        CallStep { params }
+    }
+}
+
+impl From<CallStep> for Vec<GValue> {
+    fn from(step: CallStep) -> Self {
+        step.params
+    }
+}
+
+impl From<()> for CallStep {
+    fn from(_: ()) -> Self {
+        CallStep::new(vec![])
+    }
+}
+
+impl From<&str> for CallStep {
+    fn from(service: &str) -> Self {
+        CallStep::new(vec![service.into()])
+    }
+}
+
+impl From<String> for CallStep {
+    fn from(service: String) -> Self {
+        CallStep::new(vec![service.into()])
+    }
+}
+
+impl From<(&str, Map)> for CallStep {
+    fn from((service, params): (&str, Map)) -> Self {
+        CallStep::new(vec![service.into(), params.into()])
+    }
+}
+
+impl From<(&str, HashMap<GKey, GValue>)> for CallStep {
+    fn from((service, params): (&str, HashMap<GKey, GValue>)) -> Self {
+        CallStep::new(vec![service.into(), params.into()])
+    }
+}
+
+impl From<(&str, TraversalBuilder)> for CallStep {
+    fn from((service, child): (&str, TraversalBuilder)) -> Self {
+        CallStep::new(vec![service.into(), child.bytecode.into()])
+    }
+}
+
+impl From<(&str, Map, TraversalBuilder)> for CallStep {
+    fn from((service, params, child): (&str, Map, TraversalBuilder)) -> Self {
+        CallStep::new(vec![service.into(), params.into(), child.bytecode.into()])
+    }
+}
+
+impl From<(&str, HashMap<GKey, GValue>, TraversalBuilder)> for CallStep {
+    fn from((service, params, child): (&str, HashMap<GKey, GValue>, TraversalBuilder)) -> Self {
+        CallStep::new(vec![service.into(), params.into(), child.bytecode.into()])
+    }
+}
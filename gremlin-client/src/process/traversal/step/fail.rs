@@ -0,0 +1,35 @@
+use crate::structure::GValue;
+
+pub struct FailStep {
+    params: Vec<GValue>,
+}
+
+impl FailStep {
+    fn new(params: Vec<GValue>) -> Self {
+        FailStep { params }
+    }
+}
+
+impl From<FailStep> for Vec<GValue> {
+    fn from(step: FailStep) -> Self {
+        step.params
+    }
+}
+
+impl From<()> for FailStep {
+    fn from(_: ()) -> FailStep {
+        FailStep::new(vec![])
+    }
+}
+
+impl From<&str> for FailStep {
+    fn from(message: &str) -> FailStep {
+        FailStep::new(vec![String::from(message).into()])
+    }
+}
+
+impl From<String> for FailStep {
+    fn from(message: String) -> FailStep {
+        FailStep::new(vec![message.into()])
+    }
+}
@@ -0,0 +1,357 @@
+use crate::prelude::{traversal::Bytecode, GValue, GremlinError, GremlinResult};
+use std::collections::HashSet;
+
+/// Functions understood by the server-side math expression evaluator.
+const FUNCTIONS: &[&str] = &[
+    "abs", "acos", "asin", "atan", "cbrt", "ceil", "cos", "cosh", "exp", "expm1", "floor", "log",
+    "log10", "log1p", "log2", "pow", "signum", "sin", "sinh", "sqrt", "tan", "tanh",
+];
+
+/// Builtin constants, used when no label of the same name is in scope.
+const CONSTANTS: &[&str] = &["pi", "π", "e", "φ"];
+
+/// Steps that pass their incoming traverser through unchanged.
+const FILTERS: &[&str] = &[
+    "as",
+    "and",
+    "barrier",
+    "coin",
+    "cyclicPath",
+    "dedup",
+    "filter",
+    "has",
+    "hasId",
+    "hasKey",
+    "hasLabel",
+    "hasNot",
+    "hasValue",
+    "is",
+    "limit",
+    "not",
+    "or",
+    "order",
+    "range",
+    "sample",
+    "simplePath",
+    "skip",
+    "tail",
+    "timeLimit",
+    "where",
+    "aggregate",
+    "store",
+    "sideEffect",
+    "profile",
+    "by",
+    "with",
+    "option",
+    "emit",
+    "until",
+    "times",
+];
+
+/// Steps that never emit a Map.
+const NOT_MAPS: &[&str] = &[
+    "V",
+    "E",
+    "out",
+    "in",
+    "both",
+    "outE",
+    "inE",
+    "bothE",
+    "outV",
+    "inV",
+    "bothV",
+    "otherV",
+    "count",
+    "sum",
+    "min",
+    "max",
+    "mean",
+    "math",
+    "id",
+    "label",
+    "key",
+    "value",
+    "values",
+    "properties",
+    "addV",
+    "addE",
+    "fold",
+    "path",
+];
+
+/// The variable bound to the incoming traverser.
+const CURRENT: &str = "_";
+
+pub struct MathStep {
+    expression: String,
+}
+
+impl MathStep {
+    fn new(expression: String) -> Self {
+        MathStep { expression }
+    }
+
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Variables referenced by the expression, in order of first appearance.
+    /// `by()` modulators following the step are applied to these in the same order.
+    pub fn variables(&self) -> Vec<String> {
+        variables(&self.expression)
+    }
+}
+
+impl From<MathStep> for Vec<GValue> {
+    fn from(step: MathStep) -> Self {
+        vec![step.expression.into()]
+    }
+}
+
+impl From<&str> for MathStep {
+    fn from(param: &str) -> MathStep {
+        MathStep::new(String::from(param))
+    }
+}
+
+impl From<String> for MathStep {
+    fn from(param: String) -> MathStep {
+        MathStep::new(param)
+    }
+}
+
+fn variables(expression: &str) -> Vec<String> {
+    let mut variables: Vec<String> = vec![];
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() || c == '.' {
+            // Skip numeric literals, including exponents such as `1e-3`.
+            while let Some(&n) = chars.peek() {
+                if n.is_ascii_digit() || n == '.' {
+                    chars.next();
+                } else if n == 'e' || n == 'E' {
+                    chars.next();
+                    if let Some('+') | Some('-') = chars.peek() {
+                        chars.next();
+                    }
+                } else {
+                    break;
+                }
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let mut name = String::from(c);
+            while let Some(&n) = chars.peek() {
+                if n.is_alphanumeric() || n == '_' {
+                    name.push(n);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+
+            let mut lookahead = chars.clone();
+            while let Some(n) = lookahead.peek() {
+                if n.is_whitespace() {
+                    lookahead.next();
+                } else {
+                    break;
+                }
+            }
+            let is_call = lookahead.peek() == Some(&'(');
+
+            if is_call || FUNCTIONS.contains(&name.as_str()) {
+                continue;
+            }
+            if !variables.contains(&name) {
+                variables.push(name);
+            }
+        }
+    }
+
+    variables
+}
+
+/// Checks that every variable used by a `math()` step, other than `_` and the builtin constants,
+/// refers to a label or side-effect key declared earlier in the traversal.
+///
+/// Variables may also name keys of an incoming Map, which are only known to the server, so
+/// `math()` steps whose traverser may be a Map (e.g. after `inject()`, `valueMap()` or
+/// `select()`) are not checked.
+pub(crate) fn validate_math(bytecode: &Bytecode) -> GremlinResult<()> {
+    let mut scope = HashSet::new();
+
+    for source in bytecode.sources() {
+        if source.operator() == "withSideEffect" {
+            if let Some(GValue::String(key)) = source.args().first() {
+                scope.insert(key.clone());
+            }
+        }
+    }
+
+    validate_scope(bytecode, &mut scope, true)
+}
+
+fn validate_scope(
+    bytecode: &Bytecode,
+    scope: &mut HashSet<String>,
+    mut may_be_map: bool,
+) -> GremlinResult<()> {
+    for step in bytecode.steps() {
+        let args = step.args();
+        let operator = step.operator().as_str();
+
+        match operator {
+            "as" | "project" => {
+                scope.extend(args.iter().filter_map(|arg| match arg {
+                    GValue::String(label) => Some(label.clone()),
+                    _ => None,
+                }));
+            }
+            "aggregate" | "store" | "group" | "groupCount" | "subgraph" | "tree" => {
+                if let Some(GValue::String(key)) = args.first() {
+                    scope.insert(key.clone());
+                }
+            }
+            "math" if !may_be_map => {
+                let expression = match args.first() {
+                    Some(GValue::String(expression)) => expression,
+                    _ => {
+                        return Err(GremlinError::Validation(String::from(
+                            "math() requires an expression",
+                        )))
+                    }
+                };
+
+                for variable in variables(expression) {
+                    if variable != CURRENT
+                        && !scope.contains(&variable)
+                        && !CONSTANTS.contains(&variable.as_str())
+                    {
+                        return Err(GremlinError::Validation(format!(
+                            "math(\"{}\") references `{}`, which is not a label declared by a preceding step",
+                            expression, variable
+                        )));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        // Children see the labels of their parent, and labels declared inside children
+        // (e.g. within `match()`) are visible to the steps that follow.
+        for arg in args {
+            if let GValue::Bytecode(child) = arg {
+                let mut child_scope = scope.clone();
+                validate_scope(child, &mut child_scope, may_be_map)?;
+                scope.extend(child_scope);
+            }
+        }
+
+        if NOT_MAPS.contains(&operator) {
+            may_be_map = false;
+        } else if !FILTERS.contains(&operator) {
+            may_be_map = true;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_math, MathStep};
+    use crate::process::traversal::{traversal, __};
+
+    #[test]
+    fn math_variables() {
+        let step = MathStep::from("_ * 2 + a - sqrt(b) / pi + 1e-3 * a");
+
+        // Like the server, constants are listed as they may name labels too.
+        assert_eq!(vec!["_", "a", "b", "pi"], step.variables());
+    }
+
+    #[test]
+    fn math_variables_ignore_unknown_functions() {
+        let step = MathStep::from("foo (a) + bar_1");
+
+        assert_eq!(vec!["a", "bar_1"], step.variables());
+    }
+
+    #[test]
+    fn validate_math_labels() {
+        let g = traversal().empty();
+
+        let t = g
+            .v(())
+            .as_("a")
+            .out("knows")
+            .as_("b")
+            .math("a + b")
+            .by("age");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).as_("a").math("_ + a + b").by("age");
+
+        assert!(validate_math(t.bytecode()).is_err());
+
+        let t = g.v(()).math("_ * 2");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).as_("e").out(()).math("_ - e").by("age");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).math("2 * pi * e");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+    }
+
+    #[test]
+    fn validate_math_skips_map_traversers() {
+        let g = traversal().empty();
+
+        let t = g.inject(1).math("a + 1");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).value_map(()).math("a + b");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g
+            .v(())
+            .as_("x")
+            .out(())
+            .as_("y")
+            .select(vec!["x", "y"])
+            .math("x + y");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).value_map(()).out(()).has_label("person").math("a");
+
+        assert!(validate_math(t.bytecode()).is_err());
+    }
+
+    #[test]
+    fn validate_math_nested() {
+        let g = traversal().empty();
+
+        let t = g.v(()).as_("a").out(()).map(__.math("a - _").by("age"));
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).out(()).map(__.as_("a")).math("a");
+
+        assert!(validate_math(t.bytecode()).is_ok());
+
+        let t = g.v(()).out(()).map(__.math("a - _"));
+
+        assert!(validate_math(t.bytecode()).is_err());
+    }
+}
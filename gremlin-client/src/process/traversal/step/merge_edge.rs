@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::prelude::{traversal::TraversalBuilder, GKey, GValue};
+use crate::prelude::{traversal::TraversalBuilder, GKey, GValue, Map};
 
 pub struct MergeEdgeStep {
     params: Vec<GValue>,
@@ -30,6 +30,28 @@ impl From<HashMap<GKey, GValue>> for MergeEdgeStep {
     }
 }
 
+impl From<Map> for MergeEdgeStep {
+    fn from(value: Map) -> Self {
+        MergeEdgeStep::new(vec![value.into()])
+    }
+}
+
+/// Builds the search map from key/value pairs, so `T` and `Direction` keys can be mixed, e.g.
+/// `vec![(T::Label.into(), "knows".into()), (Direction::Out.into(), 1.into())]`.
+impl<K, V> From<Vec<(K, V)>> for MergeEdgeStep
+where
+    K: Into<GKey>,
+    V: Into<GValue>,
+{
+    fn from(value: Vec<(K, V)>) -> Self {
+        let map = value
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect::<HashMap<GKey, GValue>>();
+        Self::from(map)
+    }
+}
+
 impl<K, V> From<(K, V)> for MergeEdgeStep
 where
     K: Into<GKey>,
@@ -1,43 +1,59 @@
 mod by;
+mod call;
 mod choose;
 mod coalesce;
+mod concat;
 mod dedup;
+mod fail;
+mod filter;
 mod from;
 mod has;
 mod limit;
 mod local;
 mod loops;
 mod match_step;
+mod math;
 mod merge_edge;
 mod merge_vertex;
 mod not;
 mod option;
 mod or;
+mod page_rank;
 mod repeat;
 mod select;
 mod side_effect;
+mod substring;
 mod to;
 mod until;
 mod where_step;
+mod with;
 
 pub use by::*;
+pub use call::*;
 pub use choose::*;
 pub use coalesce::*;
+pub use concat::*;
 pub use dedup::*;
+pub use fail::*;
+pub use filter::*;
 pub use from::*;
 pub use has::*;
 pub use limit::*;
 pub use local::*;
 pub use loops::*;
 pub use match_step::*;
+pub use math::*;
 pub use merge_edge::*;
 pub use merge_vertex::*;
 pub use not::*;
 pub use option::*;
 pub use or::*;
+pub use page_rank::*;
 pub use repeat::*;
 pub use select::*;
 pub use side_effect::*;
+pub use substring::*;
 pub use to::*;
 pub use until::*;
 pub use where_step::*;
+pub use with::*;
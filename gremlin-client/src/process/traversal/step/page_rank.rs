@@ -0,0 +1,29 @@
+use crate::structure::GValue;
+
+pub struct PageRankStep {
+    params: Vec<GValue>,
+}
+
+impl PageRankStep {
+    fn new(params: Vec<GValue>) -> Self {
+        PageRankStep { params }
+    }
+}
+
+impl From<PageRankStep> for Vec<GValue> {
+    fn from(step: PageRankStep) -> Self {
+        step.params
+    }
+}
+
+impl From<()> for PageRankStep {
+    fn from(_: ()) -> PageRankStep {
+        PageRankStep::new(vec![])
+    }
+}
+
+impl From<f64> for PageRankStep {
+    fn from(alpha: f64) -> PageRankStep {
+        PageRankStep::new(vec![alpha.into()])
+    }
+}
@@ -1,4 +1,4 @@
-use crate::prelude::{traversal::TraversalBuilder, GValue};
+use crate::prelude::{traversal::TraversalBuilder, GValue, Lambda};
 
 pub struct SideEffectStep {
     params: Vec<GValue>,
@@ -21,3 +21,9 @@ impl From<TraversalBuilder> for SideEffectStep {
         SideEffectStep::new(vec![param.bytecode.into()])
     }
 }
+
+impl From<Lambda> for SideEffectStep {
+    fn from(param: Lambda) -> Self {
+        SideEffectStep::new(vec![param.into()])
+    }
+}
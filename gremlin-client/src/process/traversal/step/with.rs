@@ -0,0 +1,33 @@
+use crate::structure::GValue;
+
+/// Arguments of the `with()` modulator: an option key, optionally followed by its value.
+pub struct WithStep {
+    params: Vec<GValue>,
+}
+
+impl WithStep {
+    fn new(params: Vec<GValue>) -> Self {
+        WithStep { params }
+    }
+}
+
+impl From<WithStep> for Vec<GValue> {
+    fn from(step: WithStep) -> Self {
+        step.params
+    }
+}
+
+impl From<&str> for WithStep {
+    fn from(key: &str) -> WithStep {
+        WithStep::new(vec![key.into()])
+    }
+}
+
+impl<V> From<(&str, V)> for WithStep
+where
+    V: Into<GValue>,
+{
+    fn from((key, value): (&str, V)) -> WithStep {
+        WithStep::new(vec![key.into(), value.into()])
+    }
+}
@@ -0,0 +1,171 @@
+use super::{class, TraversalStrategy, DECORATION};
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::GValue;
+
+/// Restricts the graph to the elements matched by the given filters.
+#[derive(Default, Clone)]
+pub struct SubgraphStrategy {
+    vertices: Option<TraversalBuilder>,
+    edges: Option<TraversalBuilder>,
+    vertex_properties: Option<TraversalBuilder>,
+    check_adjacent_vertices: Option<bool>,
+}
+
+impl SubgraphStrategy {
+    pub fn new() -> SubgraphStrategy {
+        Default::default()
+    }
+
+    pub fn vertices(mut self, filter: TraversalBuilder) -> Self {
+        self.vertices = Some(filter);
+        self
+    }
+
+    pub fn edges(mut self, filter: TraversalBuilder) -> Self {
+        self.edges = Some(filter);
+        self
+    }
+
+    pub fn vertex_properties(mut self, filter: TraversalBuilder) -> Self {
+        self.vertex_properties = Some(filter);
+        self
+    }
+
+    pub fn check_adjacent_vertices(mut self, check: bool) -> Self {
+        self.check_adjacent_vertices = Some(check);
+        self
+    }
+}
+
+impl From<SubgraphStrategy> for TraversalStrategy {
+    fn from(strategy: SubgraphStrategy) -> Self {
+        let mut result = TraversalStrategy::new(class(DECORATION, "SubgraphStrategy"));
+        if let Some(vertices) = strategy.vertices {
+            result = result.config("vertices", vertices);
+        }
+        if let Some(edges) = strategy.edges {
+            result = result.config("edges", edges);
+        }
+        if let Some(vertex_properties) = strategy.vertex_properties {
+            result = result.config("vertexProperties", vertex_properties);
+        }
+        if let Some(check) = strategy.check_adjacent_vertices {
+            result = result.config("checkAdjacentVertices", check);
+        }
+        result
+    }
+}
+
+/// Partitions the graph on the value of `partition_key`.
+#[derive(Clone)]
+pub struct PartitionStrategy {
+    partition_key: String,
+    write_partition: Option<String>,
+    read_partitions: Vec<String>,
+    include_meta_properties: Option<bool>,
+}
+
+impl PartitionStrategy {
+    pub fn new<K>(partition_key: K) -> PartitionStrategy
+    where
+        K: Into<String>,
+    {
+        PartitionStrategy {
+            partition_key: partition_key.into(),
+            write_partition: None,
+            read_partitions: vec![],
+            include_meta_properties: None,
+        }
+    }
+
+    pub fn write_partition<P>(mut self, partition: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.write_partition = Some(partition.into());
+        self
+    }
+
+    pub fn read_partition<P>(mut self, partition: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.read_partitions.push(partition.into());
+        self
+    }
+
+    pub fn include_meta_properties(mut self, include: bool) -> Self {
+        self.include_meta_properties = Some(include);
+        self
+    }
+}
+
+impl From<PartitionStrategy> for TraversalStrategy {
+    fn from(strategy: PartitionStrategy) -> Self {
+        let mut result = TraversalStrategy::new(class(DECORATION, "PartitionStrategy"))
+            .config("partitionKey", strategy.partition_key);
+        if let Some(write_partition) = strategy.write_partition {
+            result = result.config("writePartition", write_partition);
+        }
+        if !strategy.read_partitions.is_empty() {
+            let read_partitions: Vec<GValue> = strategy
+                .read_partitions
+                .into_iter()
+                .map(GValue::from)
+                .collect();
+            result = result.config("readPartitions", read_partitions);
+        }
+        if let Some(include) = strategy.include_meta_properties {
+            result = result.config("includeMetaProperties", include);
+        }
+        result
+    }
+}
+
+/// Passes arbitrary options to the traversal, readable by providers and steps.
+#[derive(Default, Clone)]
+pub struct OptionsStrategy {
+    options: Vec<(String, GValue)>,
+}
+
+impl OptionsStrategy {
+    pub fn new() -> OptionsStrategy {
+        Default::default()
+    }
+
+    pub fn option<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.options.push((key.into(), value.into()));
+        self
+    }
+}
+
+impl From<OptionsStrategy> for TraversalStrategy {
+    fn from(strategy: OptionsStrategy) -> Self {
+        strategy.options.into_iter().fold(
+            TraversalStrategy::new(class(DECORATION, "OptionsStrategy")),
+            |result, (key, value)| result.config(key, value),
+        )
+    }
+}
+
+/// Makes `sample()`, `coin()` and `Order.shuffle` deterministic.
+#[derive(Clone)]
+pub struct SeedStrategy {
+    seed: i64,
+}
+
+impl SeedStrategy {
+    pub fn new(seed: i64) -> SeedStrategy {
+        SeedStrategy { seed }
+    }
+}
+
+impl From<SeedStrategy> for TraversalStrategy {
+    fn from(strategy: SeedStrategy) -> Self {
+        TraversalStrategy::new(class(DECORATION, "SeedStrategy")).config("seed", strategy.seed)
+    }
+}
@@ -1 +1,181 @@
+//! Traversal strategies applied by the server, configured through
+//! [`with_strategies`](crate::process::traversal::GraphTraversalSource::with_strategies) and
+//! [`without_strategies`](crate::process::traversal::GraphTraversalSource::without_strategies).
 
+mod decoration;
+mod verification;
+
+pub use decoration::*;
+pub use verification::*;
+
+use crate::structure::GValue;
+use std::collections::HashMap;
+
+const DECORATION: &str = "org.apache.tinkerpop.gremlin.process.traversal.strategy.decoration";
+const VERIFICATION: &str = "org.apache.tinkerpop.gremlin.process.traversal.strategy.verification";
+
+/// A strategy identified by its fully qualified Java class name, plus its configuration.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TraversalStrategy {
+    class: String,
+    configuration: HashMap<String, GValue>,
+}
+
+impl TraversalStrategy {
+    pub fn new<C>(class: C) -> TraversalStrategy
+    where
+        C: Into<String>,
+    {
+        TraversalStrategy {
+            class: class.into(),
+            configuration: HashMap::new(),
+        }
+    }
+
+    pub fn config<K, V>(mut self, key: K, value: V) -> TraversalStrategy
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.configuration.insert(key.into(), value.into());
+        self
+    }
+
+    /// The fully qualified class name, e.g.
+    /// `org.apache.tinkerpop.gremlin.process.traversal.strategy.verification.ReadOnlyStrategy`.
+    pub fn class(&self) -> &str {
+        &self.class
+    }
+
+    /// The simple class name, e.g. `ReadOnlyStrategy`.
+    pub fn name(&self) -> &str {
+        self.class.rsplit('.').next().unwrap_or(&self.class)
+    }
+
+    pub fn configuration(&self) -> &HashMap<String, GValue> {
+        &self.configuration
+    }
+}
+
+const FINALIZATION: &str = "org.apache.tinkerpop.gremlin.process.traversal.strategy.finalization";
+const OPTIMIZATION: &str = "org.apache.tinkerpop.gremlin.process.traversal.strategy.optimization";
+const COMPUTER_DECORATION: &str =
+    "org.apache.tinkerpop.gremlin.process.computer.traversal.strategy.decoration";
+
+/// Strategies shipped with TinkerPop, by package.
+const KNOWN_STRATEGIES: &[(&str, &[&str])] = &[
+    (
+        DECORATION,
+        &[
+            "ConnectiveStrategy",
+            "ElementIdStrategy",
+            "EventStrategy",
+            "HaltedTraverserStrategy",
+            "OptionsStrategy",
+            "PartitionStrategy",
+            "RequirementsStrategy",
+            "SackStrategy",
+            "SeedStrategy",
+            "SideEffectStrategy",
+            "SubgraphStrategy",
+        ],
+    ),
+    (COMPUTER_DECORATION, &["VertexProgramStrategy"]),
+    (
+        FINALIZATION,
+        &[
+            "ComputerFinalizationStrategy",
+            "MatchAlgorithmStrategy",
+            "ProfileStrategy",
+            "ReferenceElementStrategy",
+        ],
+    ),
+    (
+        OPTIMIZATION,
+        &[
+            "AdjacentToIncidentStrategy",
+            "ByModulatorOptimizationStrategy",
+            "CountStrategy",
+            "EarlyLimitStrategy",
+            "FilterRankingStrategy",
+            "IdentityRemovalStrategy",
+            "IncidentToAdjacentStrategy",
+            "InlineFilterStrategy",
+            "LazyBarrierStrategy",
+            "MatchPredicateStrategy",
+            "OrderLimitStrategy",
+            "PathProcessorStrategy",
+            "PathRetractionStrategy",
+            "ProductiveByStrategy",
+            "RepeatUnrollStrategy",
+        ],
+    ),
+    (
+        VERIFICATION,
+        &[
+            "ComputerVerificationStrategy",
+            "EdgeLabelVerificationStrategy",
+            "LambdaRestrictionStrategy",
+            "ReadOnlyStrategy",
+            "ReservedKeysVerificationStrategy",
+            "StandardVerificationStrategy",
+            "VertexProgramRestrictionStrategy",
+        ],
+    ),
+];
+
+fn class(package: &str, name: &str) -> String {
+    format!("{}.{}", package, name)
+}
+
+/// The fully qualified class name of a TinkerPop strategy given its simple name.
+pub(crate) fn class_of(name: &str) -> Option<String> {
+    KNOWN_STRATEGIES
+        .iter()
+        .find(|(_, names)| names.contains(&name))
+        .map(|(package, _)| class(package, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReadOnlyStrategy, SubgraphStrategy, TraversalStrategy};
+    use crate::io::{GraphSONSerializer, V3};
+    use crate::process::traversal::__;
+    use crate::structure::GValue;
+    use serde_json::json;
+
+    #[test]
+    fn strategy_names() {
+        let strategy: TraversalStrategy = ReadOnlyStrategy::new().into();
+
+        assert_eq!("ReadOnlyStrategy", strategy.name());
+        assert_eq!(
+            "org.apache.tinkerpop.gremlin.process.traversal.strategy.verification.ReadOnlyStrategy",
+            strategy.class()
+        );
+    }
+
+    #[test]
+    fn strategy_serialization() {
+        let strategy: TraversalStrategy = SubgraphStrategy::new()
+            .vertices(__.has_label("person"))
+            .check_adjacent_vertices(false)
+            .into();
+
+        let serialized = V3::serialize(&GValue::Strategy(strategy)).unwrap();
+
+        assert_eq!(
+            json!({
+                "@type": "g:SubgraphStrategy",
+                "@value": {
+                    "vertices": {
+                        "@type": "g:Bytecode",
+                        "@value": { "step": [["hasLabel", "person"]], "source": [] }
+                    },
+                    "checkAdjacentVertices": false
+                }
+            }),
+            serialized
+        );
+    }
+}
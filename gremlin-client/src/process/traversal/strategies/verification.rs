@@ -0,0 +1,106 @@
+use super::{class, TraversalStrategy, VERIFICATION};
+use crate::structure::GValue;
+
+/// Rejects traversals containing mutating steps.
+#[derive(Default, Clone)]
+pub struct ReadOnlyStrategy {}
+
+impl ReadOnlyStrategy {
+    pub fn new() -> ReadOnlyStrategy {
+        ReadOnlyStrategy {}
+    }
+}
+
+impl From<ReadOnlyStrategy> for TraversalStrategy {
+    fn from(_: ReadOnlyStrategy) -> Self {
+        TraversalStrategy::new(class(VERIFICATION, "ReadOnlyStrategy"))
+    }
+}
+
+/// Requires edge labels to be specified on vertex steps such as `out()` and `bothE()`.
+#[derive(Default, Clone)]
+pub struct EdgeLabelVerificationStrategy {
+    throw_exception: Option<bool>,
+    log_warning: Option<bool>,
+}
+
+impl EdgeLabelVerificationStrategy {
+    pub fn new() -> EdgeLabelVerificationStrategy {
+        Default::default()
+    }
+
+    pub fn throw_exception(mut self, throw: bool) -> Self {
+        self.throw_exception = Some(throw);
+        self
+    }
+
+    pub fn log_warning(mut self, log: bool) -> Self {
+        self.log_warning = Some(log);
+        self
+    }
+}
+
+impl From<EdgeLabelVerificationStrategy> for TraversalStrategy {
+    fn from(strategy: EdgeLabelVerificationStrategy) -> Self {
+        let result = TraversalStrategy::new(class(VERIFICATION, "EdgeLabelVerificationStrategy"));
+        verification_config(result, strategy.throw_exception, strategy.log_warning)
+    }
+}
+
+/// Prevents the given property keys from being written.
+#[derive(Default, Clone)]
+pub struct ReservedKeysVerificationStrategy {
+    throw_exception: Option<bool>,
+    log_warning: Option<bool>,
+    keys: Vec<String>,
+}
+
+impl ReservedKeysVerificationStrategy {
+    pub fn new() -> ReservedKeysVerificationStrategy {
+        Default::default()
+    }
+
+    pub fn throw_exception(mut self, throw: bool) -> Self {
+        self.throw_exception = Some(throw);
+        self
+    }
+
+    pub fn log_warning(mut self, log: bool) -> Self {
+        self.log_warning = Some(log);
+        self
+    }
+
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.keys.push(key.into());
+        self
+    }
+}
+
+impl From<ReservedKeysVerificationStrategy> for TraversalStrategy {
+    fn from(strategy: ReservedKeysVerificationStrategy) -> Self {
+        let mut result =
+            TraversalStrategy::new(class(VERIFICATION, "ReservedKeysVerificationStrategy"));
+        if !strategy.keys.is_empty() {
+            let keys: Vec<GValue> = strategy.keys.into_iter().map(GValue::from).collect();
+            result = result.config("keys", keys);
+        }
+        verification_config(result, strategy.throw_exception, strategy.log_warning)
+    }
+}
+
+fn verification_config(
+    mut strategy: TraversalStrategy,
+    throw_exception: Option<bool>,
+    log_warning: Option<bool>,
+) -> TraversalStrategy {
+    if let Some(throw) = throw_exception {
+        strategy = strategy.config("throwException", throw);
+    }
+    if let Some(log) = log_warning {
+        strategy = strategy.config("logWarning", log);
+    }
+    strategy
+}
@@ -0,0 +1,442 @@
+//! Converts [`Bytecode`] back into Gremlin text, e.g. for logging, for submitting through
+//! [`execute`](crate::GremlinClient::execute) to servers that only accept scripts, or for
+//! pasting into the Gremlin console.
+
+use crate::prelude::{GremlinError, GremlinResult, ToGValue};
+use crate::process::traversal::bytecode::Instruction;
+use crate::process::traversal::{Bytecode, Order, Scope};
+use crate::structure::{Cardinality, Column, Direction, GKey, GValue, Merge, TextP, P, T};
+
+/// The flavour of Gremlin produced by a [`Translator`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Dialect {
+    /// Gremlin-Groovy, as evaluated by the `gremlin-groovy` script engine.
+    Groovy,
+    /// The language-agnostic `gremlin-lang` grammar.
+    GremlinLang,
+}
+
+#[derive(Debug, Clone)]
+pub struct Translator {
+    traversal_source: String,
+    dialect: Dialect,
+}
+
+impl Default for Translator {
+    fn default() -> Self {
+        Translator::new("g")
+    }
+}
+
+impl Translator {
+    /// A Groovy translator whose traversals start from `traversal_source`.
+    pub fn new<S>(traversal_source: S) -> Translator
+    where
+        S: Into<String>,
+    {
+        Translator {
+            traversal_source: traversal_source.into(),
+            dialect: Dialect::Groovy,
+        }
+    }
+
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    pub fn traversal_source(&self) -> &str {
+        &self.traversal_source
+    }
+
+    /// The Gremlin text of `bytecode`. Fails for arguments with no Gremlin literal, such as
+    /// a `TraversalMetrics` or an empty `P.and()`.
+    pub fn translate(&self, bytecode: &Bytecode) -> GremlinResult<String> {
+        self.traversal(&self.traversal_source, bytecode)
+    }
+
+    /// A single instruction, e.g. `out('knows')`.
+    pub fn instruction(&self, instruction: &Instruction) -> GremlinResult<String> {
+        let args = instruction
+            .args()
+            .iter()
+            .map(|arg| self.value(arg))
+            .collect::<GremlinResult<Vec<_>>>()?;
+
+        Ok(format!("{}({})", instruction.operator(), args.join(", ")))
+    }
+
+    fn traversal(&self, start: &str, bytecode: &Bytecode) -> GremlinResult<String> {
+        let mut script = String::from(start);
+
+        for instruction in bytecode.sources().iter().chain(bytecode.steps()) {
+            script.push('.');
+            script.push_str(&self.instruction(instruction)?);
+        }
+        Ok(script)
+    }
+
+    pub fn value(&self, value: &GValue) -> GremlinResult<String> {
+        Ok(match value {
+            GValue::Null => String::from("null"),
+            GValue::Bool(b) => b.to_string(),
+            GValue::Int32(v) => v.to_string(),
+            GValue::Int64(v) => format!("{}L", v),
+            GValue::Float(v) => self.float(f64::from(*v), "f"),
+            GValue::Double(v) => self.float(*v, "d"),
+            GValue::String(s) => self.string(s),
+            GValue::Token(t) => self.string(t.value()),
+            GValue::Uuid(uuid) => match self.dialect {
+                Dialect::Groovy => format!("UUID.fromString('{}')", uuid),
+                Dialect::GremlinLang => format!("UUID(\"{}\")", uuid),
+            },
+            GValue::Date(date) | GValue::Timestamp(date) => match self.dialect {
+                Dialect::Groovy => format!("new Date({}L)", date.timestamp_millis()),
+                Dialect::GremlinLang => format!(
+                    "datetime(\"{}\")",
+                    date.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+                ),
+            },
+            GValue::List(list) => self.list(list.iter())?,
+            GValue::Set(set) => match self.dialect {
+                Dialect::Groovy => format!("{} as Set", self.list(set.iter())?),
+                Dialect::GremlinLang => {
+                    let items = set
+                        .iter()
+                        .map(|v| self.value(v))
+                        .collect::<GremlinResult<Vec<_>>>()?;
+                    format!("{{{}}}", items.join(", "))
+                }
+            },
+            GValue::Map(map) => {
+                let mut entries = map
+                    .iter()
+                    .map(|(k, v)| Ok(format!("{}:{}", self.key(k)?, self.value(v)?)))
+                    .collect::<GremlinResult<Vec<_>>>()?;
+
+                // Maps are unordered; sorting keeps the output stable across runs.
+                entries.sort();
+                if entries.is_empty() {
+                    String::from("[:]")
+                } else {
+                    format!("[{}]", entries.join(", "))
+                }
+            }
+            GValue::Vertex(v) => self.value(&v.id().to_gvalue())?,
+            GValue::Edge(e) => self.value(&e.id().to_gvalue())?,
+            GValue::VertexProperty(vp) => self.value(&vp.id().to_gvalue())?,
+            GValue::P(p) => self.p(p)?,
+            GValue::TextP(p) => self.text_p(p)?,
+            GValue::T(t) => format!("T.{}", t_name(t)),
+            GValue::Order(order) => format!("Order.{}", order_name(order)),
+            GValue::Scope(scope) => format!("Scope.{}", scope_name(scope)),
+            GValue::Pop(pop) => format!("Pop.{}", pop),
+            GValue::Column(column) => format!("Column.{}", column_name(column)),
+            GValue::Direction(direction) => format!("Direction.{}", direction_name(direction)),
+            GValue::Merge(merge) => format!("Merge.{}", merge_name(merge)),
+            GValue::DT(dt) => format!("DT.{}", dt),
+            GValue::Cardinality(cardinality) => match self.dialect {
+                Dialect::Groovy => {
+                    format!(
+                        "VertexProperty.Cardinality.{}",
+                        cardinality_name(cardinality)
+                    )
+                }
+                Dialect::GremlinLang => format!("Cardinality.{}", cardinality_name(cardinality)),
+            },
+            GValue::Class(class) => String::from(class.rsplit('.').next().unwrap_or(class)),
+            GValue::Strategy(strategy) => {
+                let mut config = strategy
+                    .configuration()
+                    .iter()
+                    .map(|(k, v)| Ok(format!("{}: {}", k, self.value(v)?)))
+                    .collect::<GremlinResult<Vec<_>>>()?;
+                config.sort();
+
+                match (self.dialect, config.is_empty()) {
+                    (Dialect::Groovy, _) => {
+                        format!("new {}({})", strategy.name(), config.join(", "))
+                    }
+                    (Dialect::GremlinLang, true) => String::from(strategy.name()),
+                    (Dialect::GremlinLang, false) => {
+                        format!("{}({})", strategy.name(), config.join(", "))
+                    }
+                }
+            }
+            GValue::Bytecode(bytecode) => self.traversal("__", bytecode)?,
+            GValue::Binding(binding) => String::from(binding.key()),
+            // gremlin-lang has no lambda syntax, only Groovy can run the closure.
+            GValue::Lambda(_) if self.dialect == Dialect::GremlinLang => {
+                return Err(untranslatable("Lambda"))
+            }
+            GValue::Lambda(lambda) => {
+                let script = lambda.script().trim();
+                if script.starts_with('{') {
+                    String::from(script)
+                } else {
+                    format!("{{{}}}", script)
+                }
+            }
+            GValue::BulkSet(_) => return Err(untranslatable("BulkSet")),
+            GValue::Tree(_) => return Err(untranslatable("Tree")),
+            GValue::TinkerGraph(_) => return Err(untranslatable("TinkerGraph")),
+            GValue::Property(_) => return Err(untranslatable("Property")),
+            GValue::Path(_) => return Err(untranslatable("Path")),
+            GValue::TraversalMetrics(_) => return Err(untranslatable("TraversalMetrics")),
+            GValue::Metric(_) => return Err(untranslatable("Metric")),
+            GValue::TraversalExplanation(_) => return Err(untranslatable("TraversalExplanation")),
+            GValue::IntermediateRepr(_) => return Err(untranslatable("IntermediateRepr")),
+            GValue::Traverser(_) => return Err(untranslatable("Traverser")),
+            GValue::Geometry(_) => return Err(untranslatable("Geometry")),
+            GValue::StarGraph(_) => return Err(untranslatable("StarGraph")),
+        })
+    }
+
+    fn key(&self, key: &GKey) -> GremlinResult<String> {
+        Ok(match key {
+            GKey::String(s) => self.string(s),
+            GKey::Token(t) => self.string(t.value()),
+            GKey::T(t) => format!("(T.{})", t_name(t)),
+            GKey::Direction(d) => format!("(Direction.{})", direction_name(d)),
+            GKey::Vertex(v) => format!("({})", self.value(&v.id().to_gvalue())?),
+            GKey::Edge(e) => format!("({})", self.value(&e.id().to_gvalue())?),
+        })
+    }
+
+    fn list<'a>(&self, items: impl Iterator<Item = &'a GValue>) -> GremlinResult<String> {
+        let items = items
+            .map(|v| self.value(v))
+            .collect::<GremlinResult<Vec<_>>>()?;
+        Ok(format!("[{}]", items.join(", ")))
+    }
+
+    fn string(&self, s: &str) -> String {
+        let quote = match self.dialect {
+            Dialect::Groovy => '\'',
+            Dialect::GremlinLang => '"',
+        };
+
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push(quote);
+        for c in s.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c == quote => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                c => out.push(c),
+            }
+        }
+        out.push(quote);
+        out
+    }
+
+    fn float(&self, v: f64, suffix: &str) -> String {
+        match self.dialect {
+            Dialect::Groovy if v.is_nan() => String::from("Double.NaN"),
+            Dialect::Groovy if v == f64::INFINITY => String::from("Double.POSITIVE_INFINITY"),
+            Dialect::Groovy if v == f64::NEG_INFINITY => String::from("Double.NEGATIVE_INFINITY"),
+            Dialect::GremlinLang if v.is_nan() => String::from("NaN"),
+            Dialect::GremlinLang if v == f64::INFINITY => String::from("Infinity"),
+            Dialect::GremlinLang if v == f64::NEG_INFINITY => String::from("-Infinity"),
+            _ => format!("{}{}", v, suffix),
+        }
+    }
+
+    fn p(&self, p: &P) -> GremlinResult<String> {
+        self.predicate("P", p.operator(), p.value())
+    }
+
+    fn text_p(&self, p: &TextP) -> GremlinResult<String> {
+        self.predicate("TextP", p.operator(), p.value())
+    }
+
+    fn predicate(&self, class: &str, operator: &str, value: &GValue) -> GremlinResult<String> {
+        match (operator, value) {
+            // Connectives hold the predicates they combine.
+            ("and", GValue::List(preds)) | ("or", GValue::List(preds)) => {
+                let preds = preds
+                    .iter()
+                    .map(|p| self.value(p))
+                    .collect::<GremlinResult<Vec<_>>>()?;
+                if preds.is_empty() {
+                    return Err(GremlinError::Generic(format!(
+                        "{}.{}() needs at least one predicate",
+                        class, operator
+                    )));
+                }
+                Ok(preds.join(&format!(".{}(", operator)) + &")".repeat(preds.len() - 1))
+            }
+            ("between", GValue::List(bounds))
+            | ("inside", GValue::List(bounds))
+            | ("outside", GValue::List(bounds)) => {
+                let bounds = bounds
+                    .iter()
+                    .map(|v| self.value(v))
+                    .collect::<GremlinResult<Vec<_>>>()?;
+                Ok(format!("{}.{}({})", class, operator, bounds.join(", ")))
+            }
+            _ => Ok(format!("{}.{}({})", class, operator, self.value(value)?)),
+        }
+    }
+}
+
+fn untranslatable(kind: &str) -> GremlinError {
+    GremlinError::Generic(format!("A {} has no Gremlin literal", kind))
+}
+
+fn t_name(t: &T) -> &'static str {
+    match t {
+        T::Id => "id",
+        T::Key => "key",
+        T::Label => "label",
+        T::Value => "value",
+    }
+}
+
+fn order_name(order: &Order) -> &'static str {
+    match order {
+        Order::Asc => "asc",
+        Order::Desc => "desc",
+        Order::Shuffle => "shuffle",
+    }
+}
+
+fn scope_name(scope: &Scope) -> &'static str {
+    match scope {
+        Scope::Global => "global",
+        Scope::Local => "local",
+    }
+}
+
+fn column_name(column: &Column) -> &'static str {
+    match column {
+        Column::Keys => "keys",
+        Column::Values => "values",
+    }
+}
+
+fn direction_name(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Out | Direction::From => "OUT",
+        Direction::In | Direction::To => "IN",
+    }
+}
+
+fn merge_name(merge: &Merge) -> &'static str {
+    match merge {
+        Merge::OnCreate => "onCreate",
+        Merge::OnMatch => "onMatch",
+        Merge::OutV => "outV",
+        Merge::InV => "inV",
+    }
+}
+
+fn cardinality_name(cardinality: &Cardinality) -> &'static str {
+    match cardinality {
+        Cardinality::List => "list",
+        Cardinality::Set => "set",
+        Cardinality::Single => "single",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dialect, Translator};
+    use crate::process::traversal::strategies::PartitionStrategy;
+    use crate::process::traversal::{traversal, Bytecode, Order, __};
+    use crate::structure::{Cardinality, GValue, Lambda, Path, TextP, P, T};
+
+    #[test]
+    fn translate_groovy() {
+        let g = traversal().empty();
+
+        let t = g
+            .v(1)
+            .out("knows")
+            .has(("age", P::gt(30)))
+            .has(("name", TextP::containing("ar'k")))
+            .where_(__.out("created").values("lang"))
+            .order(())
+            .by(("age", Order::Desc))
+            .count();
+
+        assert_eq!(
+            "g.V(1).out('knows').has('age', P.gt(30)).has('name', TextP.containing('ar\\'k'))\
+             .where(__.out('created').values('lang')).order(Scope.global).by('age', Order.desc).count()",
+            Translator::default().translate(t.bytecode()).unwrap()
+        );
+    }
+
+    #[test]
+    fn translate_gremlin_lang() {
+        let g = traversal()
+            .empty()
+            .with_strategies(vec![PartitionStrategy::new("_p")
+                .write_partition("a")
+                .read_partition("a")]);
+
+        let t = g
+            .add_v("person")
+            .property_with_cardinality(Cardinality::Single, "age", 30i64)
+            .property(T::Label, "x")
+            .has(("weight", P::within(vec![0.5, 1.0])));
+
+        assert_eq!(
+            "g.withStrategies(PartitionStrategy(partitionKey: \"_p\", readPartitions: [\"a\"], writePartition: \"a\"))\
+             .addV(\"person\").property(Cardinality.single, \"age\", 30L).property(T.label, \"x\")\
+             .has(\"weight\", P.within([0.5d, 1d]))",
+            Translator::new("g")
+                .dialect(Dialect::GremlinLang)
+                .translate(t.bytecode())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn translate_lambda() {
+        let t = traversal()
+            .empty()
+            .v(())
+            .map(Lambda::new("it.get().label()"))
+            .side_effect(Lambda::new("{ println it }"));
+
+        assert_eq!(
+            "g.V().map({it.get().label()}).sideEffect({ println it })",
+            Translator::default().translate(t.bytecode()).unwrap()
+        );
+        assert!(Translator::default()
+            .dialect(Dialect::GremlinLang)
+            .translate(t.bytecode())
+            .is_err());
+    }
+
+    #[test]
+    fn translate_empty() {
+        assert_eq!(
+            "g",
+            Translator::default().translate(&Bytecode::new()).unwrap()
+        );
+        assert_eq!("__", format!("{:?}", Bytecode::new()));
+    }
+
+    #[test]
+    fn translate_untranslatable() {
+        let g = traversal().empty();
+
+        let t = g
+            .v(())
+            .has(("age", P::new("and", GValue::List(vec![].into()))));
+        assert!(Translator::default().translate(t.bytecode()).is_err());
+
+        let t = g.inject(GValue::Path(Path::new(
+            GValue::List(vec![].into()),
+            GValue::List(vec![].into()),
+        )));
+        assert!(Translator::default().translate(t.bytecode()).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use super::configure;
+use async_std::net::ToSocketAddrs;
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+pub(crate) type TcpStream = async_std::net::TcpStream;
+
+pub(crate) async fn resolve(host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+    Ok((host, port).to_socket_addrs().await?.collect())
+}
+
+pub(crate) async fn connect(
+    addr: SocketAddr,
+    keepalive: Option<Duration>,
+) -> io::Result<TcpStream> {
+    let stream = TcpStream::connect(addr).await?;
+    configure(socket2::SockRef::from(&stream), keepalive)?;
+    Ok(stream)
+}
+
+pub(crate) fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    async_std::task::spawn(future);
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await
+}
+
+#[cfg(test)]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    async_std::task::block_on(future)
+}
@@ -0,0 +1,121 @@
+use super::{connect, resolve, sleep, TcpStream};
+use futures::future::{self, Either};
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// How long an attempt may go unanswered before the next address is tried alongside it, as
+/// recommended by [RFC 8305](https://www.rfc-editor.org/rfc/rfc8305#section-5).
+const ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// Connects to the first address of `host` that accepts.
+///
+/// Addresses are tried in resolution order, alternating between IPv6 and IPv4. Each attempt
+/// gets [`ATTEMPT_DELAY`] before the next one starts racing it, and a failed attempt starts the
+/// next one right away, so an unreachable address costs little.
+pub(crate) async fn dial(
+    host: &str,
+    port: u16,
+    keepalive: Option<Duration>,
+) -> io::Result<TcpStream> {
+    let mut addrs = interleave(resolve(host, port).await?).into_iter();
+    let mut attempts = FuturesUnordered::new();
+    let mut last_error = io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("No addresses found for {}", host),
+    );
+
+    loop {
+        // Started on the first pass, after a failure or once the running attempts are slow.
+        match addrs.next() {
+            Some(addr) => attempts.push(connect(addr, keepalive)),
+            None if attempts.is_empty() => return Err(last_error),
+            None => {}
+        }
+
+        let delay = sleep(ATTEMPT_DELAY);
+        futures::pin_mut!(delay);
+        match future::select(attempts.next(), delay).await {
+            Either::Left((Some(Ok(stream)), _)) => return Ok(stream),
+            Either::Left((Some(Err(e)), _)) => last_error = e,
+            Either::Left((None, _)) | Either::Right(_) => {}
+        }
+    }
+}
+
+/// Orders `addrs` alternating between address families, starting with the family of the
+/// first one.
+fn interleave(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let preferred = match addrs.first() {
+        Some(addr) => addr.is_ipv6(),
+        None => return addrs,
+    };
+    let (first, second): (Vec<_>, Vec<_>) =
+        addrs.into_iter().partition(|a| a.is_ipv6() == preferred);
+
+    let mut first = first.into_iter();
+    let mut second = second.into_iter();
+    let mut ordered = Vec::with_capacity(first.len() + second.len());
+    loop {
+        match (first.next(), second.next()) {
+            (None, None) => return ordered,
+            (a, b) => ordered.extend(a.into_iter().chain(b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::block_on;
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn it_should_interleave_address_families() {
+        let addrs = vec![
+            addr("[::1]:8182"),
+            addr("[::2]:8182"),
+            addr("[::3]:8182"),
+            addr("127.0.0.1:8182"),
+        ];
+
+        assert_eq!(
+            vec![
+                addr("[::1]:8182"),
+                addr("127.0.0.1:8182"),
+                addr("[::2]:8182"),
+                addr("[::3]:8182"),
+            ],
+            interleave(addrs)
+        );
+    }
+
+    #[test]
+    fn it_should_dial_a_listening_socket() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let dialed = block_on(dial("127.0.0.1", port, Some(Duration::from_secs(60))));
+        assert!(dialed.is_ok());
+    }
+
+    #[test]
+    fn it_should_report_the_last_failure() {
+        // Bound and dropped, so nothing listens on the port any more.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let dialed = block_on(dial("127.0.0.1", port, None));
+        assert_eq!(
+            io::ErrorKind::ConnectionRefused,
+            dialed.err().unwrap().kind()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! The task and socket primitives the client needs from an async runtime, provided by the
+//! runtime selected with the `tokio-runtime`, `async-std-runtime` or `smol-runtime` feature.
+//!
+//! Each runtime module exposes the same items:
+//!
+//! - `TcpStream`, a socket implementing the `futures` IO traits
+//! - `resolve(host, port)`, looking up the addresses of a host
+//! - `connect(addr, keepalive)`, opening a `TcpStream` set up by [`configure`]
+//! - `spawn(future)`, running a detached task
+//! - `sleep(duration)`
+//!
+//! Everything above them, [`dial`], [`timeout`] and the WebSocket and TLS layers included, is written
+//! against `futures` only. When several runtimes are enabled, as with `--all-features` or another
+//! runtime added to the default tokio, one is picked in the order smol, async-std, tokio.
+
+use futures::future::{self, Either};
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+mod dial;
+pub(crate) use dial::dial;
+
+#[cfg(all(
+    feature = "tokio-runtime",
+    not(any(feature = "async-std-runtime", feature = "smol-runtime"))
+))]
+mod tokio_runtime;
+#[cfg(all(
+    feature = "tokio-runtime",
+    not(any(feature = "async-std-runtime", feature = "smol-runtime"))
+))]
+pub(crate) use tokio_runtime::*;
+
+#[cfg(all(feature = "async-std-runtime", not(feature = "smol-runtime")))]
+mod async_std_runtime;
+#[cfg(all(feature = "async-std-runtime", not(feature = "smol-runtime")))]
+pub(crate) use async_std_runtime::*;
+
+#[cfg(feature = "smol-runtime")]
+mod smol_runtime;
+#[cfg(feature = "smol-runtime")]
+pub(crate) use smol_runtime::*;
+
+#[cfg(not(any(
+    feature = "tokio-runtime",
+    feature = "async-std-runtime",
+    feature = "smol-runtime"
+)))]
+compile_error!("enable one of the `tokio-runtime`, `async-std-runtime` or `smol-runtime` features");
+
+/// Disables Nagle's algorithm, as requests are small and latency bound, and enables TCP
+/// keepalive probes after `keepalive` of idleness.
+fn configure(socket: socket2::SockRef<'_>, keepalive: Option<Duration>) -> io::Result<()> {
+    socket.set_nodelay(true)?;
+    if let Some(keepalive) = keepalive {
+        socket.set_tcp_keepalive(&socket2::TcpKeepalive::new().with_time(keepalive))?;
+    }
+    Ok(())
+}
+
+/// Runs `future` to completion, unless it takes longer than `duration`.
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> io::Result<F::Output> {
+    let expired = sleep(duration);
+    futures::pin_mut!(future, expired);
+    match future::select(future, expired).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("Timed out after {:?}", duration),
+        )),
+    }
+}
@@ -0,0 +1,36 @@
+use super::configure;
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+pub(crate) type TcpStream = smol::net::TcpStream;
+
+pub(crate) async fn resolve(host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+    smol::net::resolve((host, port)).await
+}
+
+pub(crate) async fn connect(
+    addr: SocketAddr,
+    keepalive: Option<Duration>,
+) -> io::Result<TcpStream> {
+    let stream = TcpStream::connect(addr).await?;
+    configure(socket2::SockRef::from(&stream), keepalive)?;
+    Ok(stream)
+}
+
+pub(crate) fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    smol::spawn(future).detach();
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    smol::Timer::after(duration).await;
+}
+
+#[cfg(test)]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    smol::block_on(future)
+}
@@ -0,0 +1,105 @@
+use crate::prelude::GValue;
+
+/// A `g:BulkSet`: every distinct value is kept once along with its bulk, the number of times it
+/// occurs. The copies are only produced while iterating, so a large bulk costs no memory.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BulkSet(Vec<(GValue, i64)>);
+
+impl BulkSet {
+    pub fn new(bulks: Vec<(GValue, i64)>) -> BulkSet {
+        BulkSet(bulks)
+    }
+
+    /// The distinct values, each with its bulk.
+    pub fn bulks(&self) -> &[(GValue, i64)] {
+        &self.0
+    }
+
+    /// The number of values, counting each as many times as its bulk.
+    pub fn len(&self) -> i64 {
+        self.0.iter().map(|(_, bulk)| (*bulk).max(0)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every value, repeated as many times as its bulk.
+    pub fn iter(&self) -> impl Iterator<Item = &GValue> {
+        self.0
+            .iter()
+            .flat_map(|(value, bulk)| std::iter::repeat(value).take((*bulk).max(0) as usize))
+    }
+}
+
+impl IntoIterator for BulkSet {
+    type Item = GValue;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter {
+            bulks: self.0.into_iter(),
+            pending: None,
+        }
+    }
+}
+
+/// Yields every value of a [`BulkSet`] as many times as its bulk, cloning one copy at a time.
+pub struct IntoIter {
+    bulks: std::vec::IntoIter<(GValue, i64)>,
+    // The current value and how many more times it is to be yielded.
+    pending: Option<(GValue, i64)>,
+}
+
+impl Iterator for IntoIter {
+    type Item = GValue;
+
+    fn next(&mut self) -> Option<GValue> {
+        loop {
+            match self.pending.take() {
+                Some((value, bulk)) if bulk > 1 => {
+                    self.pending = Some((value.clone(), bulk - 1));
+                    return Some(value);
+                }
+                Some((value, _)) => return Some(value),
+                None => {}
+            }
+
+            let (value, bulk) = self.bulks.next()?;
+            if bulk > 0 {
+                self.pending = Some((value, bulk));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BulkSet;
+    use crate::structure::GValue;
+
+    #[test]
+    fn bulk_set_expands_lazily() {
+        let set = BulkSet::new(vec![
+            (GValue::from("lop"), 3),
+            (GValue::from("vadas"), 0),
+            (GValue::from("ripple"), 1),
+        ]);
+        assert_eq!(4, set.len());
+        assert_eq!(4, set.iter().count());
+
+        let values = set.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                GValue::from("lop"),
+                GValue::from("lop"),
+                GValue::from("lop"),
+                GValue::from("ripple")
+            ],
+            values
+        );
+
+        let huge = BulkSet::new(vec![(GValue::from("x"), i64::MAX)]);
+        assert_eq!(2, huge.into_iter().take(2).count());
+    }
+}
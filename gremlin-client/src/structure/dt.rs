@@ -0,0 +1,21 @@
+use std::fmt::Display;
+
+/// Date units accepted by `dateAdd()` and friends.
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub enum DT {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Display for DT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DT::Second => write!(f, "second"),
+            DT::Minute => write!(f, "minute"),
+            DT::Hour => write!(f, "hour"),
+            DT::Day => write!(f, "day"),
+        }
+    }
+}
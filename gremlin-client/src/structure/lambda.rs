@@ -0,0 +1,50 @@
+/// A server-side function given as a script, for steps without a native equivalent.
+///
+/// The server must allow lambdas, i.e. `LambdaRestrictionStrategy` must not be active.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lambda {
+    script: String,
+    language: String,
+    arguments: i32,
+}
+
+impl Lambda {
+    /// A `gremlin-groovy` lambda with an unknown number of arguments.
+    pub fn new<S>(script: S) -> Lambda
+    where
+        S: Into<String>,
+    {
+        Lambda::with_language(script, "gremlin-groovy")
+    }
+
+    pub fn with_language<S, L>(script: S, language: L) -> Lambda
+    where
+        S: Into<String>,
+        L: Into<String>,
+    {
+        Lambda {
+            script: script.into(),
+            language: language.into(),
+            arguments: -1,
+        }
+    }
+
+    /// The number of arguments the function takes, e.g. 2 for a comparator.
+    pub fn arguments(mut self, arguments: i32) -> Lambda {
+        self.arguments = arguments;
+        self
+    }
+
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The number of arguments, or -1 when unknown.
+    pub fn arguments_count(&self) -> i32 {
+        self.arguments
+    }
+}
@@ -94,6 +94,10 @@ impl Map {
         self.0.iter()
     }
 
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut GValue> {
+        self.0.values_mut()
+    }
+
     ///Returns a reference to the value corresponding to the key.
     pub fn get<T>(&self, key: T) -> Option<&GValue>
     where
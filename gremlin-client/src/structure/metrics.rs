@@ -1,3 +1,11 @@
+use std::fmt::{self, Display};
+
+/// Steps taking at least this share (in percent) of the total duration are flagged as hotspots
+/// when a [`TraversalMetrics`] is displayed.
+pub const HOTSPOT_THRESHOLD: f64 = 50.0;
+
+const HOTSPOT_MARKER: &str = "<<";
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct TraversalExplanation {
     final_t: Vec<String>,
@@ -32,6 +40,20 @@ impl IntermediateRepr {
             category,
         }
     }
+
+    /// The traversal after the strategy was applied.
+    pub fn traversal(&self) -> &Vec<String> {
+        &self.traversal
+    }
+
+    pub fn strategy(&self) -> &String {
+        &self.strategy
+    }
+
+    /// One of `decoration`, `optimization`, `provider`, `finalization` or `verification`.
+    pub fn category(&self) -> &String {
+        &self.category
+    }
 }
 impl TraversalExplanation {
     pub fn new(
@@ -47,6 +69,50 @@ impl TraversalExplanation {
     }
 }
 
+/// Renders the explanation like the Gremlin console does: the original traversal, the
+/// traversal after each strategy (tagged with the first letter of its category) and the
+/// final traversal.
+impl Display for TraversalExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const ORIGINAL: &str = "Original Traversal";
+        const FINAL: &str = "Final Traversal";
+
+        let width = self
+            .intermediate
+            .iter()
+            .map(|i| i.strategy.len() + 5)
+            .chain(vec![ORIGINAL.len(), FINAL.len()])
+            .max()
+            .unwrap_or(0);
+
+        let traversal = |steps: &Vec<String>| format!("[{}]", steps.join(", "));
+
+        writeln!(f, "Traversal Explanation")?;
+        writeln!(f, "{}", "=".repeat(width + 40))?;
+        writeln!(f, "{:<width$}   {}", ORIGINAL, traversal(&self.original), width = width)?;
+        writeln!(f)?;
+        for step in &self.intermediate {
+            let category = step
+                .category
+                .chars()
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .unwrap_or('?');
+
+            writeln!(
+                f,
+                "{:<strategy$} [{}]   {}",
+                step.strategy,
+                category,
+                traversal(&step.traversal),
+                strategy = width - 4,
+            )?;
+        }
+        writeln!(f)?;
+        write!(f, "{:<width$}   {}", FINAL, traversal(&self.final_t), width = width)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct TraversalMetrics {
     duration: f64,
@@ -61,6 +127,89 @@ impl TraversalMetrics {
     pub fn metrics(&self) -> &Vec<Metric> {
         &self.metrics
     }
+
+    /// Steps, including nested ones, whose share of the total duration is at least
+    /// `threshold` percent, slowest first.
+    pub fn hotspots(&self, threshold: f64) -> Vec<&Metric> {
+        let mut hotspots = self
+            .iter()
+            .map(|(_, metric)| metric)
+            .filter(|m| m.perc_duration >= threshold)
+            .collect::<Vec<_>>();
+
+        hotspots.sort_by(|a, b| {
+            b.perc_duration
+                .partial_cmp(&a.perc_duration)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        hotspots
+    }
+
+    /// Depth-first iteration over the metrics and their nested metrics, paired with their
+    /// nesting depth (0 for the top-level steps).
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Metric)> {
+        let mut all = vec![];
+        for metric in &self.metrics {
+            metric.flatten(0, &mut all);
+        }
+        all.into_iter()
+    }
+
+    /// The console-style profile table, marking steps at or above `hotspot` percent of the
+    /// total duration.
+    pub fn report(&self, hotspot: f64) -> String {
+        let rows = self
+            .iter()
+            .map(|(depth, m)| (format!("{}{}", "  ".repeat(depth), m.name), m))
+            .collect::<Vec<_>>();
+
+        let width = rows
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(50);
+
+        let mut out = format!(
+            "{:<width$} {:>11} {:>11} {:>15} {:>8}\n",
+            "Step",
+            "Count",
+            "Traversers",
+            "Time (ms)",
+            "% Dur",
+            width = width
+        );
+        out.push_str(&"=".repeat(width + 50));
+        out.push('\n');
+
+        for (name, m) in &rows {
+            out.push_str(&format!(
+                "{:<width$} {:>11} {:>11} {:>15.3} {:>8.2}",
+                name,
+                m.count,
+                m.traversers,
+                m.duration,
+                m.perc_duration,
+                width = width
+            ));
+            if m.perc_duration >= hotspot {
+                out.push(' ');
+                out.push_str(HOTSPOT_MARKER);
+            }
+            out.push('\n');
+        }
+
+        out.push_str(&format!(
+            "{:>width$} {:>11} {:>11} {:>15.3} {:>8}",
+            ">TOTAL",
+            "-",
+            "-",
+            self.duration,
+            "-",
+            width = width
+        ));
+        out
+    }
 }
 
 impl TraversalMetrics {
@@ -69,6 +218,13 @@ impl TraversalMetrics {
     }
 }
 
+/// Renders the profile table, flagging steps above [`HOTSPOT_THRESHOLD`].
+impl Display for TraversalMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.report(HOTSPOT_THRESHOLD))
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Metric {
     id: String,
@@ -100,6 +256,18 @@ impl Metric {
     pub fn traversers(&self) -> &i64 {
         &self.traversers
     }
+
+    /// Metrics of the child traversals of this step, e.g. the branches of `union()`.
+    pub fn nested(&self) -> &Vec<Metric> {
+        &self.nested
+    }
+
+    fn flatten<'a>(&'a self, depth: usize, out: &mut Vec<(usize, &'a Metric)>) {
+        out.push((depth, self));
+        for nested in &self.nested {
+            nested.flatten(depth + 1, out);
+        }
+    }
 }
 
 impl Metric {
@@ -127,3 +295,88 @@ impl Metric {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{IntermediateRepr, Metric, TraversalExplanation, TraversalMetrics};
+
+    fn metrics() -> TraversalMetrics {
+        TraversalMetrics::new(
+            10.0,
+            vec![
+                Metric::new("7.0.0()", "TinkerGraphStep(vertex,[])", 1.0, 6, 6, 10.0, vec![]),
+                Metric::new(
+                    "2.0.0()",
+                    "UnionStep([[VertexStep(OUT,vertex)]])",
+                    8.0,
+                    12,
+                    12,
+                    80.0,
+                    vec![Metric::new(
+                        "3.0.0()",
+                        "VertexStep(OUT,vertex)",
+                        7.5,
+                        12,
+                        12,
+                        75.0,
+                        vec![],
+                    )],
+                ),
+                Metric::new("4.0.0()", "CountGlobalStep", 1.0, 1, 1, 10.0, vec![]),
+            ],
+        )
+    }
+
+    #[test]
+    fn metrics_hotspots() {
+        let metrics = metrics();
+
+        let names = metrics
+            .hotspots(50.0)
+            .into_iter()
+            .map(|m| m.id().as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["2.0.0()", "3.0.0()"], names);
+        assert_eq!(1, metrics.metrics()[1].nested().len());
+    }
+
+    #[test]
+    fn metrics_report() {
+        let report = metrics().to_string();
+        let lines = report.lines().collect::<Vec<_>>();
+
+        assert_eq!(7, lines.len());
+        assert!(lines[0].starts_with("Step"));
+        assert!(lines[2].starts_with("TinkerGraphStep(vertex,[])"));
+        assert!(!lines[2].ends_with("<<"));
+        assert!(lines[3].ends_with("80.00 <<"));
+        assert!(lines[4].starts_with("  VertexStep(OUT,vertex)"));
+        assert!(lines[4].ends_with("75.00 <<"));
+        assert!(lines[6].trim_start().starts_with(">TOTAL"));
+        assert!(lines[6].contains("10.000"));
+    }
+
+    #[test]
+    fn explanation_report() {
+        let explanation = TraversalExplanation::new(
+            vec![String::from("GraphStep(vertex,[])")],
+            vec![String::from("TinkerGraphStep(vertex,[])")],
+            vec![IntermediateRepr::new(
+                vec![String::from("TinkerGraphStep(vertex,[])")],
+                String::from("TinkerGraphStepStrategy"),
+                String::from("provider"),
+            )],
+        );
+
+        let report = explanation.to_string();
+        let lines = report.lines().collect::<Vec<_>>();
+
+        assert_eq!("Traversal Explanation", lines[0]);
+        assert!(lines[2].starts_with("Original Traversal"));
+        assert!(lines[2].ends_with("[GraphStep(vertex,[])]"));
+        assert!(lines[4].starts_with("TinkerGraphStepStrategy"));
+        assert!(lines[4].contains("[P]"));
+        assert!(lines[6].starts_with("Final Traversal"));
+    }
+}
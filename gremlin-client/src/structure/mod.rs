@@ -1,11 +1,14 @@
 mod bulk;
+mod bulk_set;
 mod cardinality;
 mod column;
 mod direction;
+mod dt;
 mod edge;
 mod either;
 mod gid;
 mod label;
+mod lambda;
 mod list;
 mod macros;
 mod map;
@@ -30,23 +33,28 @@ mod vertex;
 mod vertex_property;
 
 pub use bulk::Bulk;
+pub use bulk_set::BulkSet;
 pub use cardinality::Cardinality;
 pub use column::Column;
 pub use direction::Direction;
+pub use dt::DT;
 pub use edge::Edge;
 pub use either::*;
 pub use gid::{GIDs, GID};
 pub use label::Labels;
+pub use lambda::Lambda;
 pub use list::List;
 pub use map::{GKey, Map};
 pub use merge::Merge;
-pub use metrics::{IntermediateRepr, Metric, TraversalExplanation, TraversalMetrics};
+pub use metrics::{
+    IntermediateRepr, Metric, TraversalExplanation, TraversalMetrics, HOTSPOT_THRESHOLD,
+};
 pub use null::Null;
 pub use p::{IntoPredicate, P};
 pub use path::Path;
 pub use pop::Pop;
 pub use property::Property;
-pub use result::GResultSet;
+pub use result::{GResultSet, ResponseMetadata};
 pub use set::Set;
 pub use star::StarGraph;
 pub use t::T;
@@ -54,10 +62,9 @@ pub use text_p::TextP;
 pub use tinker::TinkerGraph;
 pub use token::Token;
 pub use traverser::Traverser;
-pub use tree::Tree;
+pub use tree::{Branch, DepthFirst, Tree};
 pub use value::GValue;
 pub use vertex::Vertex;
 pub use vertex_property::{GProperty, VertexProperty};
 
 pub(crate) use label::LabelType;
-pub(crate) use tree::Branch;
@@ -1,13 +1,13 @@
 use crate::io::GraphSONDeserializer;
 
+use crate::connection::Responses;
 use crate::message::Response;
-use crate::prelude::{GraphSON, GremlinClient, GremlinResult};
-use crate::structure::GValue;
+use crate::prelude::{GraphSON, GremlinClient, GremlinError, GremlinResult, ServerError};
+use crate::structure::{GValue, Map};
 use futures::Stream;
 
 use core::task::Context;
 use core::task::Poll;
-use futures::channel::mpsc::Receiver;
 use pin_project_lite::pin_project;
 use std::collections::VecDeque;
 use std::pin::Pin;
@@ -18,7 +18,7 @@ pin_project! {
         results: VecDeque<GValue>,
         pub response: Response,
         #[pin]
-        receiver: Receiver<GremlinResult<Response>>,
+        receiver: Responses,
     }
 }
 
@@ -33,11 +33,17 @@ impl<SD: GraphSON> std::fmt::Debug for GResultSet<SD> {
 }
 
 impl<SD: GraphSON> GResultSet<SD> {
+    /// The meta and status attributes of the latest response received, which is the final
+    /// one once the stream is exhausted.
+    pub fn metadata(&self) -> ResponseMetadata {
+        ResponseMetadata::new::<SD>(&self.response)
+    }
+
     pub(crate) fn new(
         client: GremlinClient<SD>,
         results: VecDeque<GValue>,
         response: Response,
-        receiver: Receiver<GremlinResult<Response>>,
+        receiver: Responses,
     ) -> GResultSet<SD> {
         GResultSet {
             client,
@@ -62,6 +68,11 @@ where
                 None => {
                     if this.response.status.code == 206 {
                         match futures::ready!(this.receiver.as_mut().poll_next(cx)) {
+                            Some(Ok(response)) if !matches!(response.status.code, 200..=206) => {
+                                let id = response.request_id;
+                                let error = ServerError::new::<SD>(id, response.status);
+                                return Poll::Ready(Some(Err(GremlinError::Server(error))));
+                            }
                             Some(Ok(response)) => {
                                 let results: VecDeque<GValue> =
                                     SD::deserialize(&response.result.data)?.into();
@@ -84,3 +95,64 @@ where
         }
     }
 }
+
+/// The `result.meta` and `status.attributes` a server sent along with a response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseMetadata {
+    meta: Map,
+    attributes: Map,
+}
+
+impl ResponseMetadata {
+    pub(crate) fn new<SD: GraphSON>(response: &Response) -> ResponseMetadata {
+        ResponseMetadata {
+            meta: response.result.decode_meta::<SD>(),
+            attributes: response.status.decode_attributes::<SD>(),
+        }
+    }
+
+    pub fn meta(&self) -> &Map {
+        &self.meta
+    }
+
+    pub fn attributes(&self) -> &Map {
+        &self.attributes
+    }
+
+    /// Looks `key` up in the status attributes, then in the meta.
+    pub fn get(&self, key: &str) -> Option<&GValue> {
+        self.attributes.get(key).or_else(|| self.meta.get(key))
+    }
+
+    /// The server that handled the request.
+    pub fn host(&self) -> Option<&str> {
+        self.string("host")
+    }
+
+    /// The side effect type, e.g. `list` or `map`, the results are to be aggregated into.
+    pub fn aggregate_to(&self) -> Option<&str> {
+        self.string("aggregateTo")
+    }
+
+    /// The warnings the server raised while processing the request.
+    pub fn warnings(&self) -> Vec<&str> {
+        match self.get("warnings") {
+            Some(GValue::List(warnings)) => warnings
+                .iter()
+                .filter_map(|w| match w {
+                    GValue::String(w) => Some(w.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            Some(GValue::String(warning)) => vec![warning.as_str()],
+            _ => vec![],
+        }
+    }
+
+    fn string(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(GValue::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,118 @@
-use crate::structure::{Edge, Vertex};
+use crate::structure::{Direction, Edge, Vertex, GID};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TinkerGraph {
     pub(crate) vertices: Vec<Vertex>,
     pub(crate) edges: Vec<Edge>,
 }
+
+impl TinkerGraph {
+    pub fn new(vertices: Vec<Vertex>, edges: Vec<Edge>) -> TinkerGraph {
+        TinkerGraph { vertices, edges }
+    }
+
+    pub fn vertices(&self) -> &Vec<Vertex> {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &Vec<Edge> {
+        &self.edges
+    }
+
+    pub fn vertex(&self, id: &GID) -> Option<&Vertex> {
+        self.vertices.iter().find(|v| v.id() == id)
+    }
+
+    pub fn edge(&self, id: &GID) -> Option<&Edge> {
+        self.edges.iter().find(|e| e.id() == id)
+    }
+
+    /// Edges incident to the vertex with the given id. `Direction::Out` (or `From`) returns the
+    /// edges leaving the vertex, `Direction::In` (or `To`) the edges arriving at it.
+    pub fn vertex_edges(&self, id: &GID, direction: Direction) -> Vec<&Edge> {
+        self.edges
+            .iter()
+            .filter(|e| match direction {
+                Direction::Out | Direction::From => e.out_v().id() == id,
+                Direction::In | Direction::To => e.in_v().id() == id,
+            })
+            .collect()
+    }
+
+    /// Vertices adjacent to the vertex with the given id, following edges in `direction`.
+    /// Endpoints that are not part of the subgraph are skipped.
+    pub fn adjacent(&self, id: &GID, direction: Direction) -> Vec<&Vertex> {
+        self.vertex_edges(id, direction.clone())
+            .into_iter()
+            .filter_map(|e| match direction {
+                Direction::Out | Direction::From => self.vertex(e.in_v().id()),
+                Direction::In | Direction::To => self.vertex(e.out_v().id()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TinkerGraph;
+    use crate::structure::{Direction, Edge, Vertex, GID};
+    use std::collections::HashMap;
+
+    fn graph() -> TinkerGraph {
+        let vertex = |id: i32, label: &str| Vertex::new(GID::Int32(id), label, HashMap::new());
+        let edge = |id: i32, out_v: i32, in_v: i32| {
+            Edge::new(
+                GID::Int32(id),
+                "knows",
+                GID::Int32(in_v),
+                "person",
+                GID::Int32(out_v),
+                "person",
+                HashMap::new(),
+            )
+        };
+
+        TinkerGraph::new(
+            vec![vertex(1, "person"), vertex(2, "person"), vertex(3, "person")],
+            vec![edge(7, 1, 2), edge(8, 1, 3), edge(9, 2, 3)],
+        )
+    }
+
+    #[test]
+    fn tinker_graph_lookup() {
+        let graph = graph();
+
+        assert_eq!(3, graph.vertices().len());
+        assert_eq!(3, graph.edges().len());
+        assert_eq!(
+            &GID::Int32(2),
+            graph.vertex(&GID::Int32(2)).unwrap().id()
+        );
+        assert_eq!(
+            &GID::Int32(3),
+            graph.edge(&GID::Int32(9)).unwrap().in_v().id()
+        );
+        assert!(graph.vertex(&GID::Int32(4)).is_none());
+    }
+
+    #[test]
+    fn tinker_graph_adjacency() {
+        let graph = graph();
+
+        let out = graph
+            .adjacent(&GID::Int32(1), Direction::Out)
+            .into_iter()
+            .map(|v| v.id().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![GID::Int32(2), GID::Int32(3)], out);
+
+        let incoming = graph
+            .vertex_edges(&GID::Int32(3), Direction::In)
+            .into_iter()
+            .map(|e| e.id().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![GID::Int32(8), GID::Int32(9)], incoming);
+    }
+}
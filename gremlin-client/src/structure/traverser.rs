@@ -15,6 +15,19 @@ impl Traverser {
         }
     }
 
+    /// How many traversers this one stands for.
+    pub fn bulk(&self) -> i64 {
+        self.bulk
+    }
+
+    pub fn value(&self) -> &GValue {
+        &self.value
+    }
+
+    pub fn into_parts(self) -> (GValue, i64) {
+        (*self.value, self.bulk)
+    }
+
     pub fn take<T>(self) -> GremlinResult<T>
     where
         T: FromGValue,
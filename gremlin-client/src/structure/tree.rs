@@ -10,3 +10,148 @@ pub struct Branch {
     pub(crate) key: Box<GValue>,
     pub(crate) value: Box<GValue>,
 }
+
+impl Tree {
+    pub fn new(branches: Vec<Branch>) -> Tree {
+        Tree { branches }
+    }
+
+    pub fn branches(&self) -> &Vec<Branch> {
+        &self.branches
+    }
+
+    pub fn len(&self) -> usize {
+        self.branches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty()
+    }
+
+    /// The keys at the root of this tree.
+    pub fn keys(&self) -> impl Iterator<Item = &GValue> {
+        self.branches.iter().map(Branch::key)
+    }
+
+    /// The subtree stored under `key`, if any.
+    pub fn get(&self, key: &GValue) -> Option<&Tree> {
+        self.branches
+            .iter()
+            .find(|b| b.key() == key)
+            .and_then(Branch::children)
+    }
+
+    /// Follows `path` from the root, returning the subtree reached by the last key.
+    pub fn walk(&self, path: &[GValue]) -> Option<&Tree> {
+        path.iter().try_fold(self, |tree, key| tree.get(key))
+    }
+
+    /// Depth-first, pre-order iteration over the keys of the tree, paired with their depth
+    /// (0 for the root keys).
+    pub fn iter(&self) -> DepthFirst<'_> {
+        DepthFirst {
+            stack: self.branches.iter().rev().map(|b| (0, b)).collect(),
+        }
+    }
+}
+
+impl Branch {
+    pub fn new(key: GValue, value: GValue) -> Branch {
+        Branch {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+    }
+
+    pub fn key(&self) -> &GValue {
+        &self.key
+    }
+
+    pub fn value(&self) -> &GValue {
+        &self.value
+    }
+
+    /// The subtree below this branch. `None` if the value is not a tree.
+    pub fn children(&self) -> Option<&Tree> {
+        match &*self.value {
+            GValue::Tree(tree) => Some(tree),
+            _ => None,
+        }
+    }
+}
+
+pub struct DepthFirst<'a> {
+    stack: Vec<(usize, &'a Branch)>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (usize, &'a GValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, branch) = self.stack.pop()?;
+
+        if let Some(children) = branch.children() {
+            self.stack
+                .extend(children.branches.iter().rev().map(|b| (depth + 1, b)));
+        }
+
+        Some((depth, branch.key()))
+    }
+}
+
+impl<'a> IntoIterator for &'a Tree {
+    type Item = (usize, &'a GValue);
+    type IntoIter = DepthFirst<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Branch, Tree};
+    use crate::structure::GValue;
+
+    fn leaf(key: &str) -> Branch {
+        Branch::new(key.into(), GValue::Tree(Tree::new(vec![])))
+    }
+
+    #[test]
+    fn tree_walk_and_iter() {
+        let tree = Tree::new(vec![
+            Branch::new(
+                "marko".into(),
+                GValue::Tree(Tree::new(vec![
+                    Branch::new(
+                        "lop".into(),
+                        GValue::Tree(Tree::new(vec![leaf("josh")])),
+                    ),
+                    leaf("vadas"),
+                ])),
+            ),
+            leaf("peter"),
+        ]);
+
+        let visited = tree
+            .iter()
+            .map(|(depth, key)| (depth, key.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (0, "marko".into()),
+                (1, "lop".into()),
+                (2, "josh".into()),
+                (1, "vadas".into()),
+                (0, "peter".into()),
+            ],
+            visited
+        );
+
+        let lop = tree.walk(&["marko".into(), "lop".into()]).unwrap();
+
+        assert_eq!(vec![&GValue::from("josh")], lop.keys().collect::<Vec<_>>());
+        assert!(tree.walk(&["peter".into(), "lop".into()]).is_none());
+    }
+}
@@ -1,6 +1,8 @@
 use crate::conversion::{BorrowFromGValue, FromGValue};
 use crate::prelude::{GremlinError, GremlinResult, ToGValue, GID};
-use crate::process::traversal::{Bytecode, Order, Scope, TraversalBuilder};
+use crate::process::traversal::{
+    strategies::TraversalStrategy, Binding, Bytecode, Order, Scope, TraversalBuilder, Translator,
+};
 use crate::structure::traverser::Traverser;
 use crate::structure::*;
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
@@ -44,6 +46,7 @@ pub enum GValue {
     P(P),
     T(T),
     Bytecode(Bytecode),
+    Strategy(TraversalStrategy),
     Traverser(Traverser),
     Scope(Scope),
     Order(Order),
@@ -54,10 +57,13 @@ pub enum GValue {
     Geometry(geo_types::Geometry),
     Merge(Merge),
     Direction(Direction),
+    DT(DT),
     Column(Column),
-    BulkSet(Map),
+    BulkSet(BulkSet),
     Class(String),
     StarGraph(StarGraph),
+    Lambda(Lambda),
+    Binding(Binding),
 }
 
 impl GValue {
@@ -104,6 +110,7 @@ impl std::fmt::Debug for GValue {
             GValue::P(_) => write!(f, "P"),
             GValue::T(t) => write!(f, "{:?}", t),
             GValue::Bytecode(bytecode) => write!(f, "{:?}", bytecode),
+            GValue::Strategy(strategy) => write!(f, "{}", strategy.name()),
             GValue::Traverser(_) => write!(f, "Traverser"),
             GValue::Scope(_) => write!(f, "Scope"),
             GValue::Order(_) => write!(f, "Order"),
@@ -114,10 +121,13 @@ impl std::fmt::Debug for GValue {
             GValue::Geometry(_) => write!(f, "Geometry"),
             GValue::Merge(_) => write!(f, "Merge"),
             GValue::Direction(_) => write!(f, "Direction"),
+            GValue::DT(dt) => write!(f, "DT.{}", dt),
             GValue::Column(_) => write!(f, "Column"),
             GValue::BulkSet(_) => write!(f, "BulkSet"),
             GValue::Class(class) => write!(f, "{}", class),
             GValue::StarGraph(star) => write!(f, "{:?}", star),
+            GValue::Lambda(lambda) => write!(f, "{}", lambda.script()),
+            GValue::Binding(binding) => write!(f, "{}={:?}", binding.key(), binding.value()),
             GValue::Tree(tree) => write!(f, "{:?}", tree),
             GValue::TinkerGraph(graph) => write!(f, "{:?}", graph),
         }
@@ -126,43 +136,18 @@ impl std::fmt::Debug for GValue {
 
 impl std::fmt::Debug for Bytecode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", &self.step_instructions[0])?;
-
-        for step in self.step_instructions.iter().skip(1) {
-            write!(f, ".{:?}", &step)?;
+        match Translator::new("__").translate(self) {
+            Ok(script) => write!(f, "{}", script),
+            Err(e) => write!(f, "<{}>", e),
         }
-
-        Ok(())
     }
 }
 
 impl std::fmt::Debug for crate::process::traversal::bytecode::Instruction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if !self.args.is_empty() {
-            match &self.args[0] {
-                GValue::P(_) => write!(f, "P{:?}", &self.args[0])?,
-                GValue::T(_) => write!(f, "T{:?}", &self.args[0])?,
-                _ => {
-                    write!(f, "{}", &self.operator)?;
-                    write!(f, "(")?;
-                    write!(f, "{:?}", &self.args[0])?
-                }
-            }
-
-            for arg in self.args.iter().skip(1) {
-                write!(f, ", {:?}", arg)?;
-            }
-        }
-
-        if !self.args.is_empty() {
-            match &self.args[0] {
-                GValue::P(_) | GValue::T(_) => Ok(()),
-                _ => {
-                    write!(f, ")")
-                }
-            }
-        } else {
-            Ok(())
+        match Translator::default().instruction(self) {
+            Ok(instruction) => write!(f, "{}", instruction),
+            Err(e) => write!(f, "<{}>", e),
         }
     }
 }
@@ -264,6 +249,21 @@ impl From<Path> for GValue {
         GValue::Path(val)
     }
 }
+impl From<BulkSet> for GValue {
+    fn from(val: BulkSet) -> Self {
+        GValue::BulkSet(val)
+    }
+}
+impl From<Tree> for GValue {
+    fn from(val: Tree) -> Self {
+        GValue::Tree(val)
+    }
+}
+impl From<TinkerGraph> for GValue {
+    fn from(val: TinkerGraph) -> Self {
+        GValue::TinkerGraph(val)
+    }
+}
 impl From<Edge> for GValue {
     fn from(val: Edge) -> Self {
         GValue::Edge(val)
@@ -329,6 +329,12 @@ impl From<Direction> for GValue {
     }
 }
 
+impl From<DT> for GValue {
+    fn from(value: DT) -> Self {
+        GValue::DT(value)
+    }
+}
+
 impl From<Column> for GValue {
     fn from(value: Column) -> Self {
         GValue::Column(value)
@@ -347,6 +353,12 @@ impl From<HashMap<String, GValue>> for GValue {
     }
 }
 
+impl From<Map> for GValue {
+    fn from(val: Map) -> Self {
+        GValue::Map(val)
+    }
+}
+
 impl From<HashMap<GKey, GValue>> for GValue {
     fn from(val: HashMap<GKey, GValue>) -> Self {
         GValue::Map(Map::from(val))
@@ -376,6 +388,7 @@ impl From<GValue> for VecDeque<GValue> {
         match val {
             GValue::List(l) => VecDeque::from(l.take()),
             GValue::Set(l) => VecDeque::from(l.take()),
+            GValue::BulkSet(l) => l.into_iter().collect(),
             _ => VecDeque::from(vec![val]),
         }
     }
@@ -418,6 +431,24 @@ impl From<Bytecode> for GValue {
     }
 }
 
+impl From<TraversalStrategy> for GValue {
+    fn from(value: TraversalStrategy) -> Self {
+        GValue::Strategy(value)
+    }
+}
+
+impl From<Lambda> for GValue {
+    fn from(value: Lambda) -> Self {
+        GValue::Lambda(value)
+    }
+}
+
+impl From<Binding> for GValue {
+    fn from(value: Binding) -> Self {
+        GValue::Binding(value)
+    }
+}
+
 impl From<bool> for GValue {
     fn from(val: bool) -> GValue {
         GValue::Bool(val)
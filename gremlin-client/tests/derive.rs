@@ -0,0 +1,33 @@
+#![cfg(feature = "derive")]
+
+use gremlin_client::derive::gremlin;
+use gremlin_client::process::traversal::{traversal, __};
+use gremlin_client::structure::{GValue, P};
+
+#[test]
+fn it_should_expand_gremlin_against_the_client() {
+    let g = traversal().empty();
+    let name = "marko";
+
+    let ages = gremlin!(g.V().has("person", "name", #name).out("knows").values("age"));
+    assert_eq!(
+        g.v(())
+            .has(("person", "name", name))
+            .out("knows")
+            .values("age")
+            .bytecode(),
+        ages.bytecode()
+    );
+
+    let friends = gremlin!(g
+        .V()
+        .coalesce(__.out("knows"), __.out("created"))
+        .has("age", P.gt(30)));
+    assert_eq!(
+        g.v(())
+            .coalesce::<GValue, _>(vec![__.out("knows"), __.out("created")])
+            .has(("age", P::gt(30)))
+            .bytecode(),
+        friends.bytecode()
+    );
+}
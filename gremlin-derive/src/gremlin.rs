@@ -0,0 +1,807 @@
+//! The `gremlin!` macro: Gremlin syntax expanded at compile time into calls on
+//! `GraphTraversalSource`, `GraphTraversal` and `TraversalBuilder`.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, bracketed, parenthesized, token, Expr, Ident, Lit, Token};
+
+const MANY: usize = usize::MAX;
+
+/// How the Gremlin arguments of a step are passed to its Rust counterpart.
+#[derive(Clone, Copy, PartialEq)]
+enum Shape {
+    /// One Rust argument per Gremlin argument.
+    Positional,
+    /// No arguments become `()`, one is passed as is, more are packed in a tuple.
+    Tuple,
+    /// No arguments become `()`, one is passed as is, more are packed in a `vec!`.
+    Many,
+}
+
+use Shape::*;
+
+/// Gremlin name, Rust method, argument shape and arity bounds.
+const STEPS: &[(&str, &str, Shape, usize, usize)] = &[
+    ("V", "v", Many, 0, MANY),
+    ("E", "e", Many, 0, MANY),
+    ("addV", "add_v", Many, 0, 1),
+    ("addE", "add_e", Many, 1, 1),
+    ("aggregate", "aggregate", Positional, 1, 1),
+    ("as", "as_", Positional, 1, 1),
+    ("asDate", "as_date", Positional, 0, 0),
+    ("asString", "as_string", Positional, 0, 0),
+    ("barrier", "barrier", Positional, 0, 0),
+    ("both", "both", Many, 0, MANY),
+    ("bothE", "both_e", Many, 0, MANY),
+    ("by", "by", Tuple, 0, 2),
+    ("call", "call", Tuple, 0, 2),
+    ("cap", "cap", Positional, 1, 1),
+    ("choose", "choose", Tuple, 1, 3),
+    ("coalesce", "coalesce", Many, 1, MANY),
+    ("combine", "combine", Positional, 1, 1),
+    ("concat", "concat", Many, 1, MANY),
+    ("conjoin", "conjoin", Positional, 1, 1),
+    (
+        "connectedComponent",
+        "connected_component",
+        Positional,
+        0,
+        0,
+    ),
+    ("constant", "constant", Positional, 1, 1),
+    ("count", "count", Positional, 0, 0),
+    ("dateAdd", "date_add", Positional, 2, 2),
+    ("dateDiff", "date_diff", Positional, 1, 1),
+    ("dedup", "dedup", Tuple, 0, 1),
+    ("difference", "difference", Positional, 1, 1),
+    ("disjunct", "disjunct", Positional, 1, 1),
+    ("drop", "drop", Positional, 0, 0),
+    ("element", "element", Positional, 0, 0),
+    ("elementMap", "element_map", Many, 0, MANY),
+    ("emit", "emit", Positional, 0, 0),
+    ("fail", "fail", Tuple, 0, 1),
+    ("filter", "filter", Positional, 1, 1),
+    ("fold", "fold", Positional, 0, 0),
+    ("format", "format", Positional, 1, 1),
+    ("from", "from", Positional, 1, 1),
+    ("group", "group", Positional, 0, 1),
+    ("groupCount", "group_count", Positional, 0, 1),
+    ("has", "has", Tuple, 1, 3),
+    ("hasId", "has_id", Positional, 1, 1),
+    ("hasLabel", "has_label", Many, 1, MANY),
+    ("hasNot", "has_not", Positional, 1, 1),
+    ("id", "id", Positional, 0, 0),
+    ("identity", "identity", Positional, 0, 0),
+    ("in", "in_", Many, 0, MANY),
+    ("inE", "in_e", Many, 0, MANY),
+    ("inV", "in_v", Positional, 0, 0),
+    ("index", "index", Positional, 0, 0),
+    ("inject", "inject", Positional, 1, 1),
+    ("intersect", "intersect", Positional, 1, 1),
+    ("is", "is", Positional, 1, 1),
+    ("label", "label", Positional, 0, 0),
+    ("length", "length", Positional, 0, 0),
+    ("limit", "limit", Positional, 1, 1),
+    ("local", "local", Positional, 1, 1),
+    ("loops", "loops", Tuple, 0, 1),
+    ("map", "map", Positional, 1, 1),
+    ("match", "match_", Many, 1, MANY),
+    ("math", "math", Positional, 1, 1),
+    ("max", "max", Tuple, 0, 1),
+    ("mean", "mean", Tuple, 0, 1),
+    ("merge", "merge", Positional, 1, 1),
+    ("mergeE", "merge_e", Positional, 1, 1),
+    ("mergeV", "merge_v", Positional, 1, 1),
+    ("min", "min", Tuple, 0, 1),
+    ("none", "none", Positional, 0, 0),
+    ("not", "not", Positional, 1, 1),
+    ("option", "option", Tuple, 1, 2),
+    ("optional", "optional", Positional, 1, 1),
+    ("or", "or", Many, 0, MANY),
+    ("order", "order", Tuple, 0, 1),
+    ("otherV", "other_v", Positional, 0, 0),
+    ("out", "out", Many, 0, MANY),
+    ("outE", "out_e", Many, 0, MANY),
+    ("outV", "out_v", Positional, 0, 0),
+    ("pageRank", "page_rank", Tuple, 0, 1),
+    ("path", "path", Positional, 0, 0),
+    ("peerPressure", "peer_pressure", Positional, 0, 0),
+    ("product", "product", Positional, 1, 1),
+    ("program", "program", Positional, 1, 1),
+    ("project", "project", Many, 1, MANY),
+    ("properties", "properties", Many, 0, MANY),
+    ("property", "property", Positional, 2, 3),
+    ("propertyMap", "property_map", Many, 0, MANY),
+    ("range", "range", Positional, 2, 2),
+    ("repeat", "repeat", Positional, 1, 1),
+    ("replace", "replace", Positional, 2, 2),
+    ("reverse", "reverse", Positional, 0, 0),
+    ("sample", "sample", Positional, 1, 1),
+    ("select", "select", Many, 1, MANY),
+    ("shortestPath", "shortest_path", Positional, 0, 0),
+    ("sideEffect", "side_effect", Positional, 1, 1),
+    ("simplePath", "simple_path", Positional, 0, 0),
+    ("split", "split", Positional, 1, 1),
+    ("subgraph", "subgraph", Positional, 1, 1),
+    ("substring", "substring", Tuple, 1, 2),
+    ("sum", "sum", Tuple, 0, 1),
+    ("to", "to", Positional, 1, 1),
+    ("toLower", "to_lower", Positional, 0, 0),
+    ("toUpper", "to_upper", Positional, 0, 0),
+    ("tree", "tree", Positional, 0, 1),
+    ("trim", "trim", Positional, 0, 0),
+    ("unfold", "unfold", Positional, 0, 0),
+    ("until", "until", Positional, 1, 1),
+    ("value", "value", Positional, 0, 0),
+    ("valueMap", "value_map", Many, 0, MANY),
+    ("values", "values", Many, 0, MANY),
+    ("where", "where_", Tuple, 1, 2),
+    ("with", "with_", Tuple, 1, 2),
+    ("withSideEffect", "with_side_effect", Tuple, 2, 2),
+];
+
+/// Steps that can be called directly on a `GraphTraversalSource`.
+const SOURCE_STEPS: &[&str] = &[
+    "V",
+    "E",
+    "addV",
+    "addE",
+    "call",
+    "inject",
+    "mergeE",
+    "mergeV",
+    "withSideEffect",
+];
+
+/// Terminal steps, only allowed at the end of a root traversal, with their arity.
+const TERMINALS: &[(&str, &str, usize)] = &[
+    ("explain", "explain", 0),
+    ("first", "first", 0),
+    ("hasNext", "has_next", 0),
+    ("iter", "iter", 0),
+    ("next", "next", 0),
+    ("one", "one", 0),
+    ("profile", "profile", 0),
+    ("toList", "to_list", 0),
+    ("tryNextN", "try_next_n", 1),
+];
+
+const PREDICATES: &[(&str, &str)] = &[
+    ("eq", "eq"),
+    ("neq", "neq"),
+    ("gt", "gt"),
+    ("gte", "gte"),
+    ("lt", "lt"),
+    ("lte", "lte"),
+    ("within", "within"),
+];
+
+const TEXT_PREDICATES: &[(&str, &str)] = &[
+    ("containing", "containing"),
+    ("startingWith", "starting_with"),
+    ("endingWith", "ending_with"),
+    ("notContaining", "not_containing"),
+    ("notStartingWith", "not_starting_with"),
+    ("notEndingWith", "not_ending_with"),
+];
+
+/// Enum types usable as `Type.value`, with the module they live in.
+const ENUMS: &[(&str, &str)] = &[
+    ("Cardinality", "structure"),
+    ("Column", "structure"),
+    ("Direction", "structure"),
+    ("DT", "structure"),
+    ("Merge", "structure"),
+    ("Order", "process::traversal"),
+    ("Pop", "structure"),
+    ("Scope", "process::traversal"),
+    ("T", "structure"),
+];
+
+/// Enum values usable without their type, as in `by(id)` or `order().by(desc)`.
+const BARE_ENUMS: &[(&str, &str)] = &[
+    ("id", "T"),
+    ("key", "T"),
+    ("label", "T"),
+    ("value", "T"),
+    ("keys", "Column"),
+    ("values", "Column"),
+    ("asc", "Order"),
+    ("desc", "Order"),
+    ("shuffle", "Order"),
+    ("global", "Scope"),
+    ("local", "Scope"),
+    ("single", "Cardinality"),
+    ("list", "Cardinality"),
+    ("set", "Cardinality"),
+    ("OUT", "Direction"),
+    ("IN", "Direction"),
+    ("onCreate", "Merge"),
+    ("onMatch", "Merge"),
+    ("outV", "Merge"),
+    ("inV", "Merge"),
+    ("first", "Pop"),
+    ("last", "Pop"),
+    ("all", "Pop"),
+    ("mixed", "Pop"),
+];
+
+fn lookup<'a>(table: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    table.iter().find(|(k, _)| *k == name).map(|(_, v)| *v)
+}
+
+/// A traversal: a Rust variable holding a `GraphTraversalSource`, or `__`, followed by steps.
+pub struct Query {
+    root: Option<Ident>,
+    steps: Vec<Step>,
+}
+
+struct Step {
+    name: Ident,
+    paren: token::Paren,
+    args: Punctuated<Arg, Token![,]>,
+}
+
+enum Arg {
+    /// `#name` or `#(expr)`: a Rust value.
+    Rust(Expr),
+    Literal(TokenStream),
+    List(Vec<Arg>),
+    Map(Vec<(Arg, Arg)>),
+    Traversal(Vec<Step>),
+    Predicate(Ident, Ident, Vec<Arg>),
+    Enum(Ident, Ident),
+}
+
+impl Parse for Query {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let root = Ident::parse_any(input)?;
+        let root = if root == "__" { None } else { Some(root) };
+        let steps = Step::parse_chain(input)?;
+
+        if steps.is_empty() {
+            return Err(input.error("expected at least one step"));
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the traversal"));
+        }
+        Ok(Query { root, steps })
+    }
+}
+
+impl Step {
+    fn parse_chain(input: ParseStream) -> syn::Result<Vec<Step>> {
+        let mut steps = vec![];
+        while input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            steps.push(Step::parse_call(input)?);
+        }
+        Ok(steps)
+    }
+
+    fn parse_call(input: ParseStream) -> syn::Result<Step> {
+        let name = Ident::parse_any(input)?;
+        let content;
+        let paren = parenthesized!(content in input);
+        let args = content.parse_terminated(Arg::parse)?;
+        Ok(Step { name, paren, args })
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![#]) {
+            input.parse::<Token![#]>()?;
+            if input.peek(token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                return Ok(Arg::Rust(content.parse()?));
+            }
+            let ident: Ident = input.parse()?;
+            return Ok(Arg::Rust(syn::parse_quote!(#ident)));
+        }
+
+        if input.peek(Token![-]) {
+            let minus = input.parse::<Token![-]>()?;
+            return match literal(input.parse()?)? {
+                Arg::Literal(lit) => Ok(Arg::Literal(quote!(#minus #lit))),
+                _ => Err(syn::Error::new_spanned(minus, "expected a number")),
+            };
+        }
+
+        if input.peek(Lit) {
+            return literal(input.parse()?);
+        }
+
+        if input.peek(token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            let items: Punctuated<Arg, Token![,]> = content.parse_terminated(Arg::parse)?;
+            return Ok(Arg::List(items.into_iter().collect()));
+        }
+
+        if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            let mut entries = vec![];
+            while !content.is_empty() {
+                let key = content.parse()?;
+                content.parse::<Token![:]>()?;
+                let value = content.parse()?;
+                entries.push((key, value));
+                if !content.is_empty() {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+            return Ok(Arg::Map(entries));
+        }
+
+        let ident = Ident::parse_any(input)?;
+        let name = ident.to_string();
+
+        if name == "__" {
+            return Ok(Arg::Traversal(Step::parse_chain(input)?));
+        }
+
+        if input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            let value = Ident::parse_any(input)?;
+            return match name.as_str() {
+                "P" | "TextP" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let args: Punctuated<Arg, Token![,]> = content.parse_terminated(Arg::parse)?;
+                    predicate(ident, value, args.into_iter().collect())
+                }
+                _ if lookup(ENUMS, &name).is_some() => Ok(Arg::Enum(ident, value)),
+                _ => Err(syn::Error::new(
+                    ident.span(),
+                    format!("unknown type `{}`", name),
+                )),
+            };
+        }
+
+        if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let args: Punctuated<Arg, Token![,]> = content.parse_terminated(Arg::parse)?;
+            let args = args.into_iter().collect::<Vec<_>>();
+
+            if lookup(PREDICATES, &name).is_some() {
+                return predicate(Ident::new("P", ident.span()), ident, args);
+            }
+            if lookup(TEXT_PREDICATES, &name).is_some() {
+                return predicate(Ident::new("TextP", ident.span()), ident, args);
+            }
+
+            // A traversal started without `__.`, as in `where(out("knows"))`.
+            let paren = token::Paren(ident.span());
+            let mut steps = vec![Step {
+                name: ident,
+                paren,
+                args: args.into_iter().collect(),
+            }];
+            steps.extend(Step::parse_chain(input)?);
+            return Ok(Arg::Traversal(steps));
+        }
+
+        match lookup(BARE_ENUMS, &name) {
+            Some(ty) => Ok(Arg::Enum(Ident::new(ty, ident.span()), ident)),
+            None => Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "unknown identifier `{}`, use `#{}` to pass a Rust value",
+                    name, name
+                ),
+            )),
+        }
+    }
+}
+
+fn predicate(class: Ident, name: Ident, args: Vec<Arg>) -> syn::Result<Arg> {
+    let table = if class == "P" {
+        PREDICATES
+    } else {
+        TEXT_PREDICATES
+    };
+    if lookup(table, &name.to_string()).is_none() {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("unknown predicate `{}.{}`", class, name),
+        ));
+    }
+    if args.is_empty() || (name != "within" && args.len() > 1) {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("wrong number of arguments for `{}`", name),
+        ));
+    }
+    Ok(Arg::Predicate(class, name, args))
+}
+
+/// Gremlin number suffixes: `L` for longs, `d` for doubles and `f` for floats.
+fn literal(lit: Lit) -> syn::Result<Arg> {
+    let span = lit.span();
+    let number = |digits: &str, suffix: &str| -> syn::Result<TokenStream> {
+        let ty = match suffix {
+            "" => return Ok(digits.parse()?),
+            "L" | "l" => "i64",
+            "d" | "D" => "f64",
+            "f" | "F" => "f32",
+            other => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("unknown number suffix `{}`", other),
+                ))
+            }
+        };
+        let digits = if ty != "i64" && !digits.contains('.') {
+            format!("{}.0", digits)
+        } else {
+            digits.to_string()
+        };
+        Ok(format!("{}{}", digits, ty).parse()?)
+    };
+
+    let tokens = match &lit {
+        Lit::Int(int) => number(int.base10_digits(), int.suffix())?,
+        Lit::Float(float) => number(float.base10_digits(), float.suffix())?,
+        Lit::Str(_) | Lit::Bool(_) => quote!(#lit),
+        _ => return Err(syn::Error::new(span, "unsupported literal")),
+    };
+    let mut stream = TokenStream::new();
+    for mut token in tokens {
+        token.set_span(span);
+        stream.extend(Some(token));
+    }
+    Ok(Arg::Literal(stream))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    /// Calls on the `GraphTraversalSource`.
+    Source,
+    /// Calls on a `GraphTraversal`.
+    Traversal,
+    /// Calls on a `TraversalBuilder`, in anonymous traversals.
+    Builder,
+}
+
+pub fn expand(query: &Query) -> syn::Result<TokenStream> {
+    match &query.root {
+        Some(source) => chain(quote!(#source), &query.steps, Context::Source),
+        None => anonymous(&query.steps),
+    }
+}
+
+fn anonymous(steps: &[Step]) -> syn::Result<TokenStream> {
+    chain(
+        quote!(gremlin_client::process::traversal::TraversalBuilder::new(
+            gremlin_client::process::traversal::Bytecode::new()
+        )),
+        steps,
+        Context::Builder,
+    )
+}
+
+fn chain(
+    mut tokens: TokenStream,
+    steps: &[Step],
+    mut context: Context,
+) -> syn::Result<TokenStream> {
+    for (i, step) in steps.iter().enumerate() {
+        let name = step.name.to_string();
+
+        if let Some((_, rust, args)) = TERMINALS.iter().find(|(gremlin, ..)| *gremlin == name) {
+            if context != Context::Traversal || i != steps.len() - 1 {
+                return Err(syn::Error::new(
+                    step.name.span(),
+                    format!("`{}` can only end a traversal started from a source", name),
+                ));
+            }
+            arity(step, *args, *args)?;
+            let method = Ident::new(rust, step.name.span());
+            let args = step
+                .args
+                .iter()
+                .map(value)
+                .collect::<syn::Result<Vec<_>>>()?;
+            tokens = quote!(#tokens.#method(#(#args),*));
+            continue;
+        }
+
+        if context == Context::Source && !SOURCE_STEPS.contains(&name.as_str()) {
+            return Err(syn::Error::new(
+                step.name.span(),
+                format!(
+                    "`{}` cannot start a traversal, expected one of {}",
+                    name,
+                    SOURCE_STEPS.join(", ")
+                ),
+            ));
+        }
+
+        let call = call(step, context)?;
+        tokens = quote!(#tokens.#call);
+        if context == Context::Source {
+            context = Context::Traversal;
+        }
+    }
+    Ok(tokens)
+}
+
+fn arity(step: &Step, min: usize, max: usize) -> syn::Result<()> {
+    let count = step.args.len();
+    if count >= min && count <= max {
+        return Ok(());
+    }
+    let expected = match (min, max) {
+        (min, max) if min == max => format!("{}", min),
+        (min, MANY) => format!("at least {}", min),
+        (min, max) => format!("{} to {}", min, max),
+    };
+    Err(syn::Error::new(
+        step.paren.span,
+        format!(
+            "`{}` takes {} argument(s) but {} were supplied",
+            step.name, expected, count
+        ),
+    ))
+}
+
+fn call(step: &Step, context: Context) -> syn::Result<TokenStream> {
+    let name = step.name.to_string();
+    let (_, rust, shape, min, max) = STEPS
+        .iter()
+        .find(|(gremlin, ..)| *gremlin == name)
+        .ok_or_else(|| syn::Error::new(step.name.span(), format!("unknown step `{}`", name)))?;
+    arity(step, *min, *max)?;
+
+    let span = step.name.span();
+    let args = step
+        .args
+        .iter()
+        .map(value)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let builder = context == Context::Builder;
+
+    let (method, args) = match (name.as_str(), args.as_slice()) {
+        ("group" | "groupCount" | "tree", []) if builder => (*rust, quote!(None)),
+        ("group" | "groupCount" | "tree", [key]) if builder => {
+            (*rust, quote!(Some(::std::convert::Into::into(#key))))
+        }
+        ("group", [key]) => ("group_as", quote!(#key)),
+        ("groupCount", [key]) => ("group_count_as", quote!(#key)),
+        ("tree", [key]) => ("tree_as", quote!(#key)),
+        ("property", [cardinality, key, value]) => (
+            "property_with_cardinality",
+            quote!(#cardinality, #key, #value),
+        ),
+        ("hasId", [id]) => (*rust, quote!(&gremlin_client::structure::GID::from(#id))),
+        (_, args) => (*rust, pack(*shape, args)),
+    };
+
+    let method = Ident::new(method, span);
+    if name == "coalesce" && !builder {
+        Ok(quote_spanned!(span=> #method::<gremlin_client::structure::GValue, _>(#args)))
+    } else {
+        Ok(quote_spanned!(span=> #method(#args)))
+    }
+}
+
+fn pack(shape: Shape, args: &[TokenStream]) -> TokenStream {
+    match (shape, args) {
+        (Positional, args) => quote!(#(#args),*),
+        (_, []) => quote!(()),
+        (_, [arg]) => quote!(#arg),
+        (Tuple, args) => quote!((#(#args),*)),
+        (Many, args) => quote!(vec![#(#args),*]),
+    }
+}
+
+fn value(arg: &Arg) -> syn::Result<TokenStream> {
+    Ok(match arg {
+        Arg::Rust(expr) => quote!(#expr),
+        Arg::Literal(lit) => lit.clone(),
+        Arg::List(items) => {
+            let items = items.iter().map(value).collect::<syn::Result<Vec<_>>>()?;
+            quote!(vec![#(#items),*])
+        }
+        Arg::Map(entries) => {
+            let mut inserts = vec![];
+            for (key, val) in entries {
+                let (key, val) = (value(key)?, value(val)?);
+                inserts.push(quote!(map.insert(#key, #val);));
+            }
+            quote!({
+                let mut map = ::std::collections::HashMap::new();
+                #(#inserts)*
+                map
+            })
+        }
+        Arg::Traversal(steps) => anonymous(steps)?,
+        Arg::Predicate(class, name, args) => {
+            let table = if class == "P" {
+                PREDICATES
+            } else {
+                TEXT_PREDICATES
+            };
+            let rust = lookup(table, &name.to_string()).unwrap_or_default();
+            let method = Ident::new(rust, name.span());
+            let args = args.iter().map(value).collect::<syn::Result<Vec<_>>>()?;
+            let arg = match args.as_slice() {
+                [arg] => quote!(#arg),
+                args => quote!(vec![#(#args),*]),
+            };
+            quote!(gremlin_client::structure::#class::#method(#arg))
+        }
+        Arg::Enum(ty, variant) => {
+            let name = ty.to_string();
+            let module = lookup(ENUMS, &name)
+                .ok_or_else(|| syn::Error::new(ty.span(), format!("unknown type `{}`", name)))?;
+            let module: TokenStream = module.parse()?;
+            let variant = Ident::new(&variant_name(&name, &variant.to_string()), variant.span());
+            quote!(gremlin_client::#module::#ty::#variant)
+        }
+    })
+}
+
+/// `Direction.OUT` becomes `Direction::Out`, `Merge.onCreate` becomes `Merge::OnCreate`.
+fn variant_name(ty: &str, value: &str) -> String {
+    let value = if ty == "Direction" {
+        value.to_lowercase()
+    } else {
+        value.to_string()
+    };
+    let mut chars = value.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, Query, STEPS};
+    use quote::quote;
+
+    fn expanded(tokens: proc_macro2::TokenStream) -> String {
+        let query: Query = syn::parse2(tokens).unwrap();
+        expand(&query).unwrap().to_string()
+    }
+
+    fn error(tokens: proc_macro2::TokenStream) -> String {
+        let query = syn::parse2::<Query>(tokens);
+        match query.and_then(|q| expand(&q)) {
+            Ok(ts) => panic!("expected an error, got {}", ts),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn expand_steps() {
+        // Passed through `quote!` as the `#name` interpolation of the macro input.
+        let name: proc_macro2::TokenStream = "#name".parse().unwrap();
+        assert_eq!(
+            quote!(g
+                .v(())
+                .has(("person", "name", name))
+                .out("knows")
+                .values("age"))
+            .to_string(),
+            expanded(quote!(g.V().has("person", "name", #name).out("knows").values("age")))
+        );
+
+        assert_eq!(
+            quote!(g.v(1i64).out_e(vec!["knows", "created"]).in_v().to_list()).to_string(),
+            expanded(quote!(g.V(1L).outE("knows", "created").inV().toList()))
+        );
+
+        assert_eq!(
+            quote!(g
+                .v(())
+                .has_id(&gremlin_client::structure::GID::from(1i64))
+                .filter(
+                    gremlin_client::process::traversal::TraversalBuilder::new(
+                        gremlin_client::process::traversal::Bytecode::new()
+                    )
+                    .out(())
+                )
+                .id()
+                .try_next_n(2))
+            .to_string(),
+            expanded(quote!(g.V().hasId(1L).filter(out()).id().tryNextN(2)))
+        );
+    }
+
+    #[test]
+    fn expand_arguments() {
+        assert_eq!(
+            quote!(g
+                .v(())
+                .where_(
+                    gremlin_client::process::traversal::TraversalBuilder::new(
+                        gremlin_client::process::traversal::Bytecode::new()
+                    )
+                    .out("knows")
+                    .has(("age", gremlin_client::structure::P::gt(30)))
+                )
+                .order(())
+                .by(("age", gremlin_client::process::traversal::Order::Desc))
+                .group_count_as("a"))
+            .to_string(),
+            expanded(quote!(g
+                .V()
+                .where(out("knows").has("age", gt(30)))
+                .order()
+                .by("age", Order.desc)
+                .groupCount("a")))
+        );
+
+        assert_eq!(
+            quote!(gremlin_client::process::traversal::TraversalBuilder::new(
+                gremlin_client::process::traversal::Bytecode::new()
+            )
+            .property_with_cardinality(
+                gremlin_client::structure::Cardinality::List,
+                "score",
+                -1.5f64
+            )
+            .group_count(None))
+            .to_string(),
+            expanded(quote!(__.property(list, "score", -1.5d).groupCount()))
+        );
+    }
+
+    #[test]
+    fn expand_errors() {
+        assert_eq!("unknown step `outt`", error(quote!(g.V().outt())));
+        assert_eq!(
+            "`range` takes 2 argument(s) but 1 were supplied",
+            error(quote!(g.V().range(1)))
+        );
+        assert!(error(quote!(g.out())).starts_with("`out` cannot start a traversal"));
+        assert_eq!(
+            "unknown identifier `name`, use `#name` to pass a Rust value",
+            error(quote!(g.V().has("name", name)))
+        );
+    }
+
+    #[test]
+    fn steps_match_the_builder() {
+        // Builder methods that have no Gremlin step of their own.
+        const NOT_STEPS: &[&str] = &[
+            "new",
+            "bytecode",
+            "has_many",
+            "other",
+            "property_many",
+            "property_with_cardinality",
+        ];
+        let builder = include_str!("../../gremlin-client/src/process/traversal/builder.rs");
+        let methods = builder
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("pub fn "))
+            .map(|line| line.split(['(', '<']).next().unwrap())
+            .collect::<Vec<_>>();
+
+        for (gremlin, rust, ..) in STEPS {
+            assert!(
+                methods.contains(rust),
+                "`{}` maps to `{}`, which the builder lacks",
+                gremlin,
+                rust
+            );
+        }
+        for method in methods {
+            assert!(
+                NOT_STEPS.contains(&method) || STEPS.iter().any(|(_, rust, ..)| *rust == method),
+                "the builder's `{}` has no entry in STEPS",
+                method
+            );
+        }
+    }
+}
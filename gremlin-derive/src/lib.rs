@@ -1,5 +1,6 @@
 use proc_macro::TokenStream;
 
+mod gremlin;
 mod value;
 
 #[proc_macro_derive(FromGValue)]
@@ -21,3 +22,24 @@ pub fn derive_from_gmap(input: TokenStream) -> TokenStream {
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+/// Expands Gremlin syntax into the traversal DSL at compile time.
+///
+/// The traversal starts from a `GraphTraversalSource` variable or from `__`. Rust values are
+/// interpolated with `#name` or `#(expr)`:
+///
+/// ```ignore
+/// let name = "marko";
+/// let ages = gremlin!(g.V().has("person", "name", #name).out("knows").values("age").toList()).await?;
+/// ```
+///
+/// Unknown steps, wrong arities and unknown identifiers are reported as compile errors.
+#[proc_macro]
+pub fn gremlin(input: TokenStream) -> TokenStream {
+    let query = syn::parse_macro_input!(input as gremlin::Query);
+
+    match gremlin::expand(&query) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
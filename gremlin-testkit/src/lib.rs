@@ -0,0 +1,34 @@
+//! An in-process stand-in for Gremlin Server, for testing `gremlin-client` without the
+//! `docker-compose` services.
+//!
+//! [`TestServer`] listens on a local port and speaks the Gremlin WebSocket protocol: requests
+//! carry the GraphSON content-type prefix, replies are streamed as `206` partial responses
+//! followed by a final `200`/`204`, credentials are negotiated through the `407` SASL
+//! challenge, and sessions are tracked until closed. Replies are scripted per test by
+//! registering handlers keyed by script, bytecode or an arbitrary predicate.
+//!
+//! [`TestProxy`] is an HTTP `CONNECT` and SOCKS5 proxy to put in front of it.
+//!
+//!```rust,no_run
+//! use gremlin_client::prelude::*;
+//! use gremlin_testkit::{Reply, TestServer};
+//!
+//! # async fn run() -> GremlinResult<()> {
+//! let server = TestServer::<V3>::start().await?;
+//! server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+//!
+//! let client = GremlinClient::connect(server.options().build()).await?;
+//! let results = client.execute("g.V().count()", &[]).await?;
+//! # Ok(())
+//! # }
+//!```
+
+mod proxy;
+mod reply;
+mod request;
+mod server;
+
+pub use proxy::{TestProxy, TestProxyBuilder};
+pub use reply::Reply;
+pub use request::{Handshake, Request};
+pub use server::{TestServer, TestServerBuilder};
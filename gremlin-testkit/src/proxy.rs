@@ -0,0 +1,244 @@
+use base64::prelude::{Engine, BASE64_STANDARD};
+use gremlin_client::prelude::{GremlinResult, ProxyOptions};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// An HTTP `CONNECT` and SOCKS5 proxy stand-in listening on a local port, tunnelling to
+/// whichever address clients ask for. Both protocols are served on the same port. The proxy
+/// stops when dropped.
+pub struct TestProxy {
+    address: SocketAddr,
+    state: Arc<State>,
+    accept: JoinHandle<()>,
+}
+
+pub struct TestProxyBuilder {
+    credentials: Option<(String, String)>,
+}
+
+#[derive(Default)]
+struct State {
+    credentials: Option<(String, String)>,
+    targets: Mutex<Vec<String>>,
+    connections: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl TestProxyBuilder {
+    /// Requires clients to authenticate, with basic authentication over HTTP and
+    /// username/password authentication over SOCKS5.
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.credentials = Some((String::from(username), String::from(password)));
+        self
+    }
+
+    pub async fn start(self) -> GremlinResult<TestProxy> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(State {
+            credentials: self.credentials,
+            ..Default::default()
+        });
+
+        let accept = tokio::spawn(accept_loop(listener, state.clone()));
+
+        Ok(TestProxy {
+            address,
+            state,
+            accept,
+        })
+    }
+}
+
+impl TestProxy {
+    pub fn builder() -> TestProxyBuilder {
+        TestProxyBuilder { credentials: None }
+    }
+
+    /// Starts a proxy that does not require authentication.
+    pub async fn start() -> GremlinResult<TestProxy> {
+        TestProxy::builder().start().await
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Options using this proxy through HTTP `CONNECT`.
+    pub fn http(&self) -> ProxyOptions {
+        ProxyOptions::http(self.address.ip().to_string(), self.address.port())
+    }
+
+    /// Options using this proxy through SOCKS5, resolving host names on the client.
+    pub fn socks5(&self) -> ProxyOptions {
+        ProxyOptions::socks5(self.address.ip().to_string(), self.address.port())
+    }
+
+    /// Options using this proxy through SOCKS5, resolving host names on the proxy.
+    pub fn socks5h(&self) -> ProxyOptions {
+        ProxyOptions::socks5h(self.address.ip().to_string(), self.address.port())
+    }
+
+    /// The `host:port` of every tunnel opened so far.
+    pub fn targets(&self) -> Vec<String> {
+        lock(&self.state.targets).clone()
+    }
+}
+
+impl Drop for TestProxy {
+    fn drop(&mut self) {
+        self.accept.abort();
+        for connection in lock(&self.state.connections).drain(..) {
+            connection.abort();
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+async fn accept_loop(listener: TcpListener, state: Arc<State>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        let connection = tokio::spawn(serve(stream, state.clone()));
+        lock(&state.connections).push(connection);
+    }
+}
+
+async fn serve(mut client: TcpStream, state: Arc<State>) {
+    let mut version = [0u8];
+    if client.read_exact(&mut version).await.is_err() {
+        return;
+    }
+    // A SOCKS5 greeting starts with its version, an HTTP request with a method name.
+    let target = if version[0] == 5 {
+        socks5(&mut client, &state).await
+    } else {
+        http(&mut client, &state, version[0]).await
+    };
+
+    if let Ok((target, mut upstream)) = target {
+        lock(&state.targets).push(target);
+        let _ = io::copy_bidirectional(&mut client, &mut upstream).await;
+    }
+}
+
+async fn http(client: &mut TcpStream, state: &State, first: u8) -> io::Result<(String, TcpStream)> {
+    let mut header = vec![first];
+    while !header.ends_with(b"\r\n\r\n") {
+        header.push(client.read_u8().await?);
+    }
+    let header = String::from_utf8_lossy(&header).into_owned();
+    let mut lines = header.lines();
+
+    let target = match lines
+        .next()
+        .map(|l| l.split_whitespace().collect::<Vec<_>>())
+    {
+        Some(request) if request.len() == 3 && request[0] == "CONNECT" => String::from(request[1]),
+        _ => {
+            client
+                .write_all(b"HTTP/1.1 405 Method Not Allowed\r\n\r\n")
+                .await?;
+            return Err(refused());
+        }
+    };
+
+    if let Some((username, password)) = &state.credentials {
+        let expected = format!(
+            "Basic {}",
+            BASE64_STANDARD.encode(format!("{}:{}", username, password))
+        );
+        let authorized = lines.any(|line| match line.split_once(':') {
+            Some((name, value)) => {
+                name.eq_ignore_ascii_case("Proxy-Authorization") && value.trim() == expected
+            }
+            None => false,
+        });
+        if !authorized {
+            client
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await?;
+            return Err(refused());
+        }
+    }
+
+    match TcpStream::connect(target.as_str()).await {
+        Ok(upstream) => {
+            client
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .await?;
+            Ok((target, upstream))
+        }
+        Err(e) => {
+            client
+                .write_all(b"HTTP/1.1 502 Bad Gateway\r\n\r\n")
+                .await?;
+            Err(e)
+        }
+    }
+}
+
+async fn socks5(client: &mut TcpStream, state: &State) -> io::Result<(String, TcpStream)> {
+    let mut methods = vec![0u8; client.read_u8().await? as usize];
+    client.read_exact(&mut methods).await?;
+
+    match &state.credentials {
+        Some((username, password)) if methods.contains(&2) => {
+            client.write_all(&[5, 2]).await?;
+            let _version = client.read_u8().await?;
+            let given_username = read_string(client).await?;
+            let given_password = read_string(client).await?;
+            if &given_username != username || &given_password != password {
+                client.write_all(&[1, 1]).await?;
+                return Err(refused());
+            }
+            client.write_all(&[1, 0]).await?;
+        }
+        None if methods.contains(&0) => client.write_all(&[5, 0]).await?,
+        _ => {
+            client.write_all(&[5, 0xFF]).await?;
+            return Err(refused());
+        }
+    }
+
+    let mut request = [0u8; 4];
+    client.read_exact(&mut request).await?;
+    let host = match request[3] {
+        1 => {
+            let mut ip = [0u8; 4];
+            client.read_exact(&mut ip).await?;
+            std::net::Ipv4Addr::from(ip).to_string()
+        }
+        4 => {
+            let mut ip = [0u8; 16];
+            client.read_exact(&mut ip).await?;
+            format!("[{}]", std::net::Ipv6Addr::from(ip))
+        }
+        _ => read_string(client).await?,
+    };
+    let target = format!("{}:{}", host, client.read_u16().await?);
+
+    match TcpStream::connect(target.as_str()).await {
+        Ok(upstream) => {
+            client.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).await?;
+            Ok((target, upstream))
+        }
+        Err(e) => {
+            client.write_all(&[5, 5, 0, 1, 0, 0, 0, 0, 0, 0]).await?;
+            Err(e)
+        }
+    }
+}
+
+async fn read_string(client: &mut TcpStream) -> io::Result<String> {
+    let mut bytes = vec![0u8; client.read_u8().await? as usize];
+    client.read_exact(&mut bytes).await?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn refused() -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, "Tunnel refused")
+}
@@ -0,0 +1,130 @@
+use gremlin_client::structure::{GValue, List};
+use std::collections::HashMap;
+
+/// The response frames sent back for a request.
+#[derive(Debug, Clone)]
+pub struct Reply {
+    pub(crate) frames: Vec<Frame>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Frame {
+    pub(crate) code: i16,
+    pub(crate) message: String,
+    pub(crate) data: Option<Vec<GValue>>,
+    pub(crate) meta: HashMap<String, GValue>,
+    pub(crate) attributes: HashMap<String, GValue>,
+}
+
+impl Reply {
+    /// A single `200` response with `values`, or `204` when there are none.
+    pub fn ok<I, V>(values: I) -> Reply
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<GValue>,
+    {
+        let values: Vec<GValue> = values.into_iter().map(Into::into).collect();
+        if values.is_empty() {
+            Reply::no_content()
+        } else {
+            Reply::frame(200, "", Some(values))
+        }
+    }
+
+    /// A `204` response.
+    pub fn no_content() -> Reply {
+        Reply::frame(204, "", None)
+    }
+
+    /// One `206` partial response per page and a final `200` with the last page, as the
+    /// server does when results exceed `resultIterationBatchSize`.
+    pub fn pages<I, P, V>(pages: I) -> Reply
+    where
+        I: IntoIterator<Item = P>,
+        P: IntoIterator<Item = V>,
+        V: Into<GValue>,
+    {
+        let mut frames: Vec<Frame> = pages
+            .into_iter()
+            .map(|page| Frame {
+                code: 206,
+                message: String::new(),
+                data: Some(page.into_iter().map(Into::into).collect()),
+                meta: HashMap::new(),
+                attributes: HashMap::new(),
+            })
+            .collect();
+
+        match frames.last_mut() {
+            Some(last) => last.code = 200,
+            None => return Reply::no_content(),
+        }
+        Reply { frames }
+    }
+
+    /// A failed request, e.g. `597` for a script evaluation error.
+    pub fn error<M>(code: i16, message: M) -> Reply
+    where
+        M: Into<String>,
+    {
+        Reply::frame(code, message, None)
+    }
+
+    /// A failed request reporting the class names of the exceptions behind it in its
+    /// `exceptions` status attribute, outermost first.
+    pub fn exception<M, I, E>(code: i16, message: M, exceptions: I) -> Reply
+    where
+        M: Into<String>,
+        I: IntoIterator<Item = E>,
+        E: Into<String>,
+    {
+        let exceptions: Vec<GValue> = exceptions
+            .into_iter()
+            .map(|e| GValue::String(e.into()))
+            .collect();
+        Reply::error(code, message)
+            .with_attribute("exceptions", GValue::List(List::new(exceptions)))
+    }
+
+    /// Adds `key` to the `result.meta` of the final response.
+    pub fn with_meta<K, V>(mut self, key: K, value: V) -> Reply
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.last().meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds `key` to the `status.attributes` of the final response.
+    pub fn with_attribute<K, V>(mut self, key: K, value: V) -> Reply
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.last().attributes.insert(key.into(), value.into());
+        self
+    }
+
+    fn last(&mut self) -> &mut Frame {
+        // Every constructor produces at least one frame.
+        self.frames
+            .last_mut()
+            .expect("a reply has at least one frame")
+    }
+
+    fn frame<M>(code: i16, message: M, data: Option<Vec<GValue>>) -> Reply
+    where
+        M: Into<String>,
+    {
+        Reply {
+            frames: vec![Frame {
+                code,
+                message: message.into(),
+                data,
+                meta: HashMap::new(),
+                attributes: HashMap::new(),
+            }],
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use async_tungstenite::tungstenite::http;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// A request received by the [`TestServer`](crate::TestServer), with its arguments left as
+/// GraphSON.
+#[derive(Debug, Clone)]
+pub struct Request {
+    mime_type: String,
+    id: Uuid,
+    op: String,
+    processor: String,
+    args: Value,
+}
+
+impl Request {
+    /// Parses a WebSocket frame: the content type, prefixed by its length, then the message.
+    pub(crate) fn parse(frame: &[u8]) -> Result<Request, String> {
+        let (len, rest) = frame
+            .split_first()
+            .ok_or_else(|| String::from("Empty frame"))?;
+        let len = *len as usize;
+        if rest.len() < len {
+            return Err(String::from("Truncated content type"));
+        }
+        let (mime_type, message) = rest.split_at(len);
+        let mime_type = String::from_utf8(mime_type.to_vec()).map_err(|e| e.to_string())?;
+
+        let message: Value = serde_json::from_slice(message).map_err(|e| e.to_string())?;
+        let id = match &message["requestId"] {
+            // GraphSON 2 wraps the id as a g:UUID.
+            Value::Object(typed) => typed.get("@value"),
+            other => Some(other),
+        }
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| String::from("Missing requestId"))?;
+
+        Ok(Request {
+            mime_type,
+            id,
+            op: message["op"].as_str().unwrap_or_default().to_string(),
+            processor: message["processor"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            args: message["args"].clone(),
+        })
+    }
+
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn op(&self) -> &str {
+        &self.op
+    }
+
+    pub fn processor(&self) -> &str {
+        &self.processor
+    }
+
+    /// The serialized arguments, a JSON object for GraphSON 2 and a `g:Map` from GraphSON 3.
+    pub fn args(&self) -> &Value {
+        &self.args
+    }
+
+    /// A single argument, e.g. `gremlin`, `bindings` or `session`.
+    pub fn arg(&self, key: &str) -> Option<&Value> {
+        match &self.args {
+            Value::Object(map) if map.get("@type") == Some(&Value::from("g:Map")) => map
+                .get("@value")
+                .and_then(Value::as_array)
+                .and_then(|entries| {
+                    entries
+                        .chunks(2)
+                        .find(|entry| entry[0].as_str() == Some(key))
+                        .and_then(|entry| entry.get(1))
+                }),
+            Value::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    /// The script of an `eval` request.
+    pub fn script(&self) -> Option<&str> {
+        self.arg("gremlin").and_then(Value::as_str)
+    }
+
+    /// The serialized `g:Bytecode` of a `bytecode` request.
+    pub fn bytecode(&self) -> Option<&Value> {
+        self.arg("gremlin").filter(|gremlin| gremlin.is_object())
+    }
+
+    /// The session the request runs in, if any.
+    pub fn session(&self) -> Option<&str> {
+        self.arg("session").and_then(Value::as_str)
+    }
+}
+
+/// A WebSocket handshake received by the [`TestServer`](crate::TestServer).
+#[derive(Debug, Clone)]
+pub struct Handshake {
+    path: String,
+    query: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl Handshake {
+    pub(crate) fn new(request: &http::Request<()>) -> Handshake {
+        Handshake {
+            path: String::from(request.uri().path()),
+            query: request.uri().query().map(String::from),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The query string, still URL encoded.
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// The first value of a header, looked up case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+}
@@ -0,0 +1,326 @@
+use crate::reply::{Frame, Reply};
+use crate::request::{Handshake, Request};
+use async_tungstenite::tokio::accept_hdr_async;
+use async_tungstenite::tungstenite::handshake::server;
+use async_tungstenite::tungstenite::http::HeaderValue;
+use async_tungstenite::tungstenite::Message;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use futures::{SinkExt, StreamExt};
+use gremlin_client::prelude::{
+    ConnectionOptions, ConnectionOptionsBuilder, GraphSON, GremlinError, GremlinResult,
+};
+use gremlin_client::process::traversal::Bytecode;
+use gremlin_client::structure::{GValue, List};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+type Handler = dyn Fn(&Request) -> Option<Reply> + Send + Sync;
+
+/// A Gremlin Server stand-in listening on a local port, serializing with `SD`.
+///
+/// Requests are answered by the most recently registered handler that accepts them, so a test
+/// can override a reply by registering another handler. `g.inject(0)`, the script the
+/// connection pool runs as a health check, is answered with `[0]` out of the box; anything
+/// without a handler fails with `597`. The server stops when dropped.
+pub struct TestServer<SD: GraphSON> {
+    address: SocketAddr,
+    state: Arc<State>,
+    accept: JoinHandle<()>,
+    serde: PhantomData<SD>,
+}
+
+pub struct TestServerBuilder<SD: GraphSON> {
+    credentials: Option<(String, String)>,
+    serde: PhantomData<SD>,
+}
+
+#[derive(Default)]
+struct State {
+    credentials: Option<(String, String)>,
+    handlers: Mutex<Vec<Arc<Handler>>>,
+    requests: Mutex<Vec<Request>>,
+    handshakes: Mutex<Vec<Handshake>>,
+    sessions: Mutex<HashSet<String>>,
+    connections: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl<SD: GraphSON> TestServerBuilder<SD> {
+    /// Requires clients to authenticate with SASL `PLAIN` before running requests.
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.credentials = Some((String::from(username), String::from(password)));
+        self
+    }
+
+    pub async fn start(self) -> GremlinResult<TestServer<SD>> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(State {
+            credentials: self.credentials,
+            ..Default::default()
+        });
+
+        let accept = tokio::spawn(accept_loop::<SD>(listener, state.clone()));
+
+        let server = TestServer {
+            address,
+            state,
+            accept,
+            serde: PhantomData,
+        };
+        server.on_script("g.inject(0)", Reply::ok(vec![0]));
+        Ok(server)
+    }
+}
+
+impl<SD: GraphSON> TestServer<SD> {
+    pub fn builder() -> TestServerBuilder<SD> {
+        TestServerBuilder {
+            credentials: None,
+            serde: PhantomData,
+        }
+    }
+
+    /// Starts a server that does not require authentication.
+    pub async fn start() -> GremlinResult<TestServer<SD>> {
+        TestServer::builder().start().await
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Options connecting to this server, to be completed with e.g. credentials or a pool size.
+    pub fn options(&self) -> ConnectionOptionsBuilder<SD> {
+        ConnectionOptions::builder()
+            .host(self.address.ip().to_string())
+            .port(self.address.port())
+            .serde(SD::default())
+    }
+
+    /// Answers `eval` requests for exactly `script`.
+    pub fn on_script<S>(&self, script: S, reply: Reply) -> &Self
+    where
+        S: Into<String>,
+    {
+        let script = script.into();
+        self.handle(move |request| {
+            if request.script() == Some(script.as_str()) {
+                Some(reply.clone())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Answers `bytecode` requests whose traversal serializes to the same GraphSON as
+    /// `bytecode`.
+    pub fn on_bytecode(&self, bytecode: &Bytecode, reply: Reply) -> GremlinResult<&Self> {
+        let expected = SD::serialize(&GValue::Bytecode(bytecode.clone()))?;
+        Ok(self.handle(move |request| {
+            if request.bytecode() == Some(&expected) {
+                Some(reply.clone())
+            } else {
+                None
+            }
+        }))
+    }
+
+    /// Answers the requests for which `handler` returns a reply.
+    pub fn handle<F>(&self, handler: F) -> &Self
+    where
+        F: Fn(&Request) -> Option<Reply> + Send + Sync + 'static,
+    {
+        lock(&self.state.handlers).push(Arc::new(handler));
+        self
+    }
+
+    /// Every request received so far, including authentication and session requests.
+    pub fn requests(&self) -> Vec<Request> {
+        lock(&self.state.requests).clone()
+    }
+
+    /// The WebSocket handshake of every connection opened so far.
+    pub fn handshakes(&self) -> Vec<Handshake> {
+        lock(&self.state.handshakes).clone()
+    }
+
+    /// The sessions opened by requests and not closed yet.
+    pub fn sessions(&self) -> HashSet<String> {
+        lock(&self.state.sessions).clone()
+    }
+}
+
+impl<SD: GraphSON> Drop for TestServer<SD> {
+    fn drop(&mut self) {
+        self.accept.abort();
+        for connection in lock(&self.state.connections).drain(..) {
+            connection.abort();
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A handler panicking on one connection must not take the whole server down.
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+async fn accept_loop<SD: GraphSON>(listener: TcpListener, state: Arc<State>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        let connection = tokio::spawn(serve::<SD>(stream, state.clone()));
+        lock(&state.connections).push(connection);
+    }
+}
+
+async fn serve<SD: GraphSON>(stream: TcpStream, state: Arc<State>) {
+    let callback = |request: &server::Request, mut response: server::Response| {
+        lock(&state.handshakes).push(Handshake::new(request));
+        // Clients fail handshakes that ignore the subprotocols they asked for.
+        let subprotocol = request
+            .headers()
+            .get("Sec-WebSocket-Protocol")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .and_then(|value| HeaderValue::from_str(value.trim()).ok());
+        if let Some(subprotocol) = subprotocol {
+            response
+                .headers_mut()
+                .insert("Sec-WebSocket-Protocol", subprotocol);
+        }
+        Ok(response)
+    };
+    let websocket = match accept_hdr_async(stream, callback).await {
+        Ok(websocket) => websocket,
+        Err(_) => return,
+    };
+    let (mut sink, mut source) = websocket.split();
+    let mut authenticated = state.credentials.is_none();
+    let mut challenged: Option<Request> = None;
+
+    while let Some(Ok(message)) = source.next().await {
+        let frame = match message {
+            Message::Binary(data) => data,
+            Message::Text(text) => text.into_bytes(),
+            Message::Ping(data) => {
+                let _ = sink.send(Message::Pong(data)).await;
+                continue;
+            }
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        let request = match Request::parse(&frame) {
+            Ok(request) => request,
+            // Without a request id there is no one to reply to.
+            Err(_) => continue,
+        };
+        lock(&state.requests).push(request.clone());
+
+        let reply = if request.mime_type() != SD::content_type() {
+            Reply::error(
+                499,
+                format!("Unsupported mime type {}", request.mime_type()),
+            )
+        } else if request.op() == "authentication" {
+            match challenged.take() {
+                Some(original) if state.verify(&request) => {
+                    authenticated = true;
+                    state.dispatch(&original)
+                }
+                _ => Reply::error(401, "Username and/or password are incorrect"),
+            }
+        } else if !authenticated {
+            challenged = Some(request.clone());
+            Reply::error(407, "Authentication required")
+        } else {
+            state.dispatch(&request)
+        };
+
+        for frame in reply.frames {
+            let response = match response::<SD>(request.id(), frame) {
+                Ok(response) => response,
+                Err(e) => response::<SD>(
+                    request.id(),
+                    Frame {
+                        code: 599,
+                        message: e.to_string(),
+                        data: None,
+                        meta: HashMap::new(),
+                        attributes: HashMap::new(),
+                    },
+                )
+                .expect("error responses always serialize"),
+            };
+            if sink.send(Message::Binary(response)).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+impl State {
+    fn verify(&self, request: &Request) -> bool {
+        let (username, password) = match &self.credentials {
+            Some(credentials) => credentials,
+            None => return true,
+        };
+        request
+            .arg("sasl")
+            .and_then(Value::as_str)
+            .and_then(|sasl| BASE64_STANDARD.decode(sasl).ok())
+            .map_or(false, |sasl| {
+                sasl == format!("\0{}\0{}", username, password).into_bytes()
+            })
+    }
+
+    fn dispatch(&self, request: &Request) -> Reply {
+        if request.processor() == "session" && request.op() == "close" {
+            if let Some(session) = request.session() {
+                lock(&self.sessions).remove(session);
+            }
+            return Reply::no_content();
+        }
+        if let Some(session) = request.session() {
+            lock(&self.sessions).insert(String::from(session));
+        }
+
+        // Handlers run without the lock held, so they may register further handlers.
+        let handlers = lock(&self.handlers).clone();
+        handlers
+            .iter()
+            .rev()
+            .find_map(|handler| handler(request))
+            .unwrap_or_else(|| {
+                let gremlin = request
+                    .script()
+                    .map(String::from)
+                    .or_else(|| request.bytecode().map(Value::to_string))
+                    .unwrap_or_default();
+                Reply::error(597, format!("No handler registered for {}", gremlin))
+            })
+    }
+}
+
+fn response<SD: GraphSON>(id: &Uuid, frame: Frame) -> Result<Vec<u8>, GremlinError> {
+    let data = match frame.data {
+        Some(values) => SD::serialize(&GValue::List(List::new(values)))?,
+        None => Value::Null,
+    };
+    let response = json!({
+        "requestId": id,
+        "status": {
+            "code": frame.code,
+            "message": frame.message,
+            "attributes": SD::serialize(&GValue::from(frame.attributes))?,
+        },
+        "result": {
+            "data": data,
+            "meta": SD::serialize(&GValue::from(frame.meta))?,
+        },
+    });
+    Ok(serde_json::to_vec(&response)?)
+}
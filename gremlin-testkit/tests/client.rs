@@ -0,0 +1,530 @@
+use gremlin_client::prelude::*;
+use gremlin_client::structure::{IntermediateRepr, TraversalExplanation};
+use gremlin_testkit::{Reply, TestProxy, TestServer};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+async fn collect(results: GResultSet<V3>) -> GremlinResult<Vec<GValue>> {
+    results.collect::<Vec<_>>().await.into_iter().collect()
+}
+
+#[tokio::test]
+async fn it_should_reply_to_scripts() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+
+    let results = collect(client.execute("g.V().count()", &[]).await.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(vec![GValue::Int64(6)], results);
+
+    let missing = client.execute("g.E().count()", &[]).await;
+    assert!(matches!(
+        missing,
+        Err(GremlinError::Server(e)) if e.code() == ResponseStatusCode::ScriptEvaluationError
+    ));
+
+    assert!(server
+        .requests()
+        .iter()
+        .any(|r| r.script() == Some("g.V().count()")));
+}
+
+#[tokio::test]
+async fn it_should_stream_partial_responses() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let g = traversal().with_remote(client);
+
+    let names = g.v(()).has_label("person").values("name");
+    server
+        .on_bytecode(
+            names.bytecode(),
+            Reply::pages(vec![vec!["marko", "vadas"], vec!["josh"]]),
+        )
+        .unwrap();
+
+    let results = names.to_list().await.unwrap();
+    assert_eq!(
+        vec![
+            GValue::from("marko"),
+            GValue::from("vadas"),
+            GValue::from("josh")
+        ],
+        results
+    );
+}
+
+#[tokio::test]
+async fn it_should_authenticate() {
+    let server = TestServer::<V3>::builder()
+        .credentials("stephen", "password")
+        .start()
+        .await
+        .unwrap();
+    server.on_script("g.V().count()", Reply::ok(vec![1_i64]));
+
+    let client =
+        GremlinClient::connect(server.options().credentials("stephen", "password").build())
+            .await
+            .unwrap();
+    let results = collect(client.execute("g.V().count()", &[]).await.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(vec![GValue::Int64(1)], results);
+
+    let client = GremlinClient::connect(server.options().credentials("stephen", "wrong").build())
+        .await
+        .unwrap();
+    let denied = client.execute("g.V().count()", &[]).await;
+    assert!(denied.is_err());
+}
+
+#[tokio::test]
+async fn it_should_track_sessions() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("x = 1", Reply::ok(vec![1]));
+
+    let mut client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let mut session = client.create_session(String::from("tests")).await.unwrap();
+
+    session.execute("x = 1", &[]).await.unwrap();
+    assert!(server.sessions().contains("tests"));
+
+    session.close_session().await.unwrap();
+    assert!(server.sessions().is_empty());
+}
+
+#[tokio::test]
+async fn it_should_expand_bulked_traversers() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let g = traversal().with_remote(client);
+
+    let names = g.v(()).out("created").values("name");
+    server
+        .on_bytecode(
+            names.bytecode(),
+            Reply::ok(vec![
+                Traverser::new(3, GValue::from("lop")),
+                Traverser::new(1, GValue::from("ripple")),
+            ]),
+        )
+        .unwrap();
+
+    let results = names.to_list().await.unwrap();
+    assert_eq!(4, results.len());
+    assert_eq!(
+        3,
+        results
+            .iter()
+            .filter(|v| **v == GValue::from("lop"))
+            .count()
+    );
+
+    let traversers = names
+        .iter_traversers()
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<GremlinResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        vec![(GValue::from("lop"), 3), (GValue::from("ripple"), 1)],
+        traversers
+    );
+}
+
+#[tokio::test]
+async fn it_should_stop_after_the_first_results() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let g = traversal().with_remote(client);
+
+    let names = g.v(()).values("name");
+    server
+        .on_bytecode(
+            names.bytecode(),
+            Reply::pages(vec![vec!["marko", "vadas"], vec!["josh"]]),
+        )
+        .unwrap();
+    server
+        .on_bytecode(
+            g.v(()).values("name").limit(1).bytecode(),
+            Reply::ok(vec!["marko"]),
+        )
+        .unwrap();
+
+    assert_eq!(Some(GValue::from("marko")), names.next().await.unwrap());
+    assert!(names.has_next().await.unwrap());
+    assert_eq!(
+        vec![GValue::from("marko"), GValue::from("vadas")],
+        names.try_next_n(2).await.unwrap()
+    );
+    assert!(names.one().await.is_err());
+    assert_eq!(Some(GValue::from("marko")), names.first().await.unwrap());
+
+    let missing = g.v(()).values("age");
+    assert!(matches!(
+        missing.next().await,
+        Err(GremlinError::Server(e)) if e.code() == ResponseStatusCode::ScriptEvaluationError
+    ));
+}
+
+#[tokio::test]
+async fn it_should_not_stall_other_requests_behind_a_slow_consumer() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V()", Reply::pages((0..10).map(|page| vec![page])));
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+
+    // A single connection, so both requests share the same socket.
+    let client = GremlinClient::connect(server.options().pool_size(1).batch_size(Some(1)).build())
+        .await
+        .unwrap();
+
+    let slow = client.execute("g.V()", &[]).await.unwrap();
+
+    let count = collect(client.execute("g.V().count()", &[]).await.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(vec![GValue::Int64(6)], count);
+
+    let results = collect(slow).await.unwrap();
+    assert_eq!((0..10).map(GValue::Int32).collect::<Vec<_>>(), results);
+
+    assert!(server
+        .requests()
+        .iter()
+        .any(|r| r.arg("batchSize").is_some()));
+}
+
+#[tokio::test]
+async fn it_should_fail_slow_consumers_past_the_result_buffer_limit() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V()", Reply::pages((0..10).map(|page| vec![page])));
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+
+    let client = GremlinClient::connect(
+        server
+            .options()
+            .pool_size(1)
+            .result_buffer_limit(Some(2))
+            .batch_size(Some(1))
+            .build(),
+    )
+    .await
+    .unwrap();
+
+    let slow = client.execute("g.V()", &[]).await.unwrap();
+
+    let count = collect(client.execute("g.V().count()", &[]).await.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(vec![GValue::Int64(6)], count);
+
+    let results = slow.collect::<Vec<_>>().await;
+    assert!(results.len() < 10);
+    assert!(matches!(
+        results.last(),
+        Some(Err(GremlinError::Generic(_)))
+    ));
+}
+
+#[tokio::test]
+async fn it_should_report_server_errors() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let g = traversal().with_remote(client);
+
+    let conflicting = g.v(1).property("name", "marko");
+    server
+        .on_bytecode(
+            conflicting.bytecode(),
+            Reply::exception(
+                500,
+                "Lock expired",
+                vec![
+                    "org.janusgraph.diskstorage.locking.PermanentLockingException",
+                    "java.util.ConcurrentModificationException",
+                ],
+            ),
+        )
+        .unwrap();
+    server
+        .on_bytecode(g.v(()).count().bytecode(), Reply::error(598, "Timed out"))
+        .unwrap();
+
+    let error = match conflicting.to_list().await {
+        Err(GremlinError::Server(error)) => error,
+        other => panic!("Expected a server error, got {:?}", other),
+    };
+    assert_eq!(ResponseStatusCode::ServerError, error.code());
+    assert_eq!("Lock expired", error.message());
+    assert!(error.has_exception("ConcurrentModificationException"));
+    assert_eq!(Some(conflicting.bytecode()), error.bytecode());
+    assert!(server
+        .requests()
+        .iter()
+        .any(|r| r.id() == error.request_id()));
+
+    assert!(matches!(
+        g.v(()).count().next().await,
+        Err(GremlinError::Server(e)) if e.code() == ResponseStatusCode::ServerTimeout
+    ));
+}
+
+#[tokio::test]
+async fn it_should_expose_response_metadata() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let g = traversal().with_remote(client);
+
+    let names = g.v(()).values("name");
+    server
+        .on_bytecode(
+            names.bytecode(),
+            Reply::pages(vec![vec!["marko"], vec!["josh"]])
+                .with_meta("aggregateTo", "list")
+                .with_attribute("host", "/127.0.0.1:8182")
+                .with_attribute("warnings", "Slow traversal")
+                .with_attribute("x-gremlin-count", 2_i64),
+        )
+        .unwrap();
+
+    let mut results = names.iter().await.unwrap();
+    assert_eq!(None, results.metadata().host());
+
+    while let Some(result) = results.next().await {
+        result.unwrap();
+    }
+    let metadata = results.metadata();
+    assert_eq!(Some("/127.0.0.1:8182"), metadata.host());
+    assert_eq!(Some("list"), metadata.aggregate_to());
+    assert_eq!(vec!["Slow traversal"], metadata.warnings());
+    assert_eq!(Some(&GValue::Int64(2)), metadata.get("x-gremlin-count"));
+}
+
+#[tokio::test]
+async fn it_should_explain_traversals_as_scripts() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    let client = GremlinClient::connect(server.options().build())
+        .await
+        .unwrap();
+    let g = traversal().with_remote(client);
+
+    let explanation = TraversalExplanation::new(
+        vec![
+            String::from("GraphStep(vertex,[])"),
+            String::from("VertexStep(OUT,[knows],vertex)"),
+        ],
+        vec![
+            String::from("TinkerGraphStep(vertex,[])"),
+            String::from("VertexStep(OUT,[knows],vertex)"),
+        ],
+        vec![IntermediateRepr::new(
+            vec![
+                String::from("TinkerGraphStep(vertex,[])"),
+                String::from("VertexStep(OUT,[knows],vertex)"),
+            ],
+            String::from("TinkerGraphStepStrategy"),
+            String::from("provider"),
+        )],
+    );
+    server.on_script(
+        "g.V().out('knows').explain()",
+        Reply::ok(vec![GValue::TraversalExplanation(explanation.clone())]),
+    );
+
+    assert_eq!(explanation, g.v(()).out("knows").explain().await.unwrap());
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("eval", requests[0].op());
+    assert_eq!(Some("g.V().out('knows').explain()"), requests[0].script());
+}
+
+#[tokio::test]
+async fn it_should_connect_through_proxies() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+    let proxy = TestProxy::builder()
+        .credentials("proxy", "secret")
+        .start()
+        .await
+        .unwrap();
+
+    for options in vec![proxy.http(), proxy.socks5()] {
+        let client = GremlinClient::connect(
+            server
+                .options()
+                .proxy(options.credentials("proxy", "secret"))
+                .build(),
+        )
+        .await
+        .unwrap();
+        let results = collect(client.execute("g.V().count()", &[]).await.unwrap())
+            .await
+            .unwrap();
+        assert_eq!(vec![GValue::Int64(6)], results);
+    }
+
+    let targets = proxy.targets();
+    assert!(!targets.is_empty());
+    assert!(targets.iter().all(|t| *t == server.address().to_string()));
+
+    for options in vec![proxy.http(), proxy.socks5()] {
+        let denied = match GremlinClient::connect(
+            server
+                .options()
+                .proxy(options.credentials("proxy", "wrong"))
+                .pool_connection_timeout(Some(Duration::from_millis(500)))
+                .build(),
+        )
+        .await
+        {
+            Ok(client) => client.execute("g.V().count()", &[]).await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        assert!(denied.is_err());
+    }
+}
+
+#[tokio::test]
+async fn it_should_resolve_host_names_for_socks5_proxies() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+
+    for (remote_dns, expected) in vec![(false, "127.0.0.1"), (true, "localhost")] {
+        let proxy = TestProxy::start().await.unwrap();
+        let options = if remote_dns {
+            proxy.socks5h()
+        } else {
+            proxy.socks5()
+        };
+        let client =
+            GremlinClient::connect(server.options().host("localhost").proxy(options).build())
+                .await
+                .unwrap();
+        collect(client.execute("g.V().count()", &[]).await.unwrap())
+            .await
+            .unwrap();
+
+        let target = format!("{}:{}", expected, server.address().port());
+        let targets = proxy.targets();
+        assert!(!targets.is_empty());
+        assert!(targets.iter().all(|t| *t == target));
+    }
+}
+
+#[tokio::test]
+async fn it_should_bypass_the_proxy_for_excluded_hosts() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+    let proxy = TestProxy::start().await.unwrap();
+
+    let client = GremlinClient::connect(
+        server
+            .options()
+            .proxy(proxy.http().no_proxy(vec!["127.0.0.1"]))
+            .build(),
+    )
+    .await
+    .unwrap();
+    collect(client.execute("g.V().count()", &[]).await.unwrap())
+        .await
+        .unwrap();
+
+    assert!(proxy.targets().is_empty());
+}
+
+#[tokio::test]
+async fn it_should_send_handshake_headers() {
+    let server = TestServer::<V3>::start().await.unwrap();
+    server.on_script("g.V().count()", Reply::ok(vec![6_i64]));
+    let tokens = Arc::new(AtomicUsize::new(0));
+    let issued = tokens.clone();
+
+    let client = GremlinClient::connect(
+        server
+            .options()
+            .pool_size(1)
+            .path("gateway/gremlin")
+            .query_param("database", "graph db")
+            .header("X-Tenant", "acme")
+            .header_provider(move |url| {
+                let token = issued.fetch_add(1, Ordering::SeqCst);
+                Ok(vec![(
+                    String::from("Authorization"),
+                    format!("Bearer {} for {}", token, url),
+                )])
+            })
+            .subprotocol("gremlin")
+            .build(),
+    )
+    .await
+    .unwrap();
+    collect(client.execute("g.V().count()", &[]).await.unwrap())
+        .await
+        .unwrap();
+
+    let handshakes = server.handshakes();
+    assert!(!handshakes.is_empty());
+    let handshake = &handshakes[0];
+    assert_eq!("/gateway/gremlin", handshake.path());
+    assert_eq!(Some("database=graph+db"), handshake.query());
+    assert_eq!(Some("acme"), handshake.header("x-tenant"));
+    assert_eq!(Some("gremlin"), handshake.header("Sec-WebSocket-Protocol"));
+    assert_eq!(
+        Some(format!(
+            "Bearer 0 for ws://{}/gateway/gremlin?database=graph+db",
+            server.address()
+        ))
+        .as_deref(),
+        handshake.header("Authorization")
+    );
+    assert!(handshake
+        .header("User-Agent")
+        .unwrap()
+        .starts_with("gremlin-client/"));
+    assert_eq!(handshakes.len(), tokens.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn it_should_fail_connecting_with_invalid_headers() {
+    let server = TestServer::<V3>::start().await.unwrap();
+
+    let result = GremlinClient::connect(
+        server
+            .options()
+            .header("X-Tenant", "line\nbreak")
+            .pool_connection_timeout(Some(Duration::from_millis(500)))
+            .build(),
+    )
+    .await;
+    let result = match result {
+        Ok(client) => client.execute("g.V().count()", &[]).await.map(|_| ()),
+        Err(e) => Err(e),
+    };
+
+    assert!(result.is_err());
+    assert!(server.handshakes().is_empty());
+}